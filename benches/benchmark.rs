@@ -19,6 +19,7 @@ fn run_benchmark(b: &mut Bencher<'_>, path: &str) -> Result<(), Error> {
     let mut state = State::new(1, Box::new(Hc128Rng::from_seed([0x41; 32])), ctx);
     let format = SqlFormat {
         escape_backslash: false,
+        mysql_axis_order: false,
     };
     let mut sink: Box<dyn Write> = Box::new(sink());
 
@@ -4,7 +4,7 @@ create table bmsql_item (
   i_name   varchar(24),
     /*{{ rand.regex('[0-9a-zA-Z]{14,24}') }}*/
   i_price  decimal(5,2),
-    /*{{ rand.range_inclusive(100, 10000)/100 }}*/
+    /*{{ decimal(rand.range_inclusive(100, 10000) / 100, 2) }}*/
   i_data   varchar(50),
     /*{{
       overlay(
@@ -4,7 +4,7 @@ create table bmsql_warehouse (
   w_ytd       decimal(12,2),
     /*{{ 300000.0 }}*/
   w_tax       decimal(4,4),
-    /*{{ rand.range_inclusive(0, 2000)/10000 }}*/
+    /*{{ decimal(rand.range_inclusive(0, 2000) / 10000, 4) }}*/
   w_name      varchar(10),
     /*{{ rand.regex('[0-9a-zA-Z]{6,10}') }}*/
   w_street_1  varchar(20),
@@ -6,7 +6,7 @@ create table bmsql_district (
   d_ytd        decimal(12,2),
     /*{{ 30000.0 }}*/
   d_tax        decimal(4,4),
-    /*{{ rand.range_inclusive(0, 2000)/10000 }}*/
+    /*{{ decimal(rand.range_inclusive(0, 2000) / 10000, 4) }}*/
   d_next_o_id  integer,
     /*{{ 3001 }}*/
   d_name       varchar(10),
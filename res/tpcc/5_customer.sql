@@ -10,7 +10,7 @@ create table bmsql_customer (
   c_id           integer not null,
     /*{{ (@c_id := mod(rownum-1, 3000)) + 1 }}*/
   c_discount     decimal(4,4),
-    /*{{ rand.range_inclusive(0, 5000)/10000 }}*/
+    /*{{ decimal(rand.range_inclusive(0, 5000) / 10000, 4) }}*/
   c_credit       char(2),
     /*{{ case when rand.bool(0.1) then 'BC' else 'GC' end }}*/
   c_last         varchar(16),
@@ -58,7 +58,7 @@ create table bmsql_order_line (
       case when @o_id <= 2100 then
         0.0
       else
-        rand.range(1, 1000000)/100
+        decimal(rand.range(1, 1000000) / 100, 2)
       end
     }}*/
   ol_supply_w_id  integer,
@@ -22,18 +22,23 @@ use rayon::{
     iter::{IntoParallelIterator, ParallelIterator},
     ThreadPoolBuilder,
 };
-use serde_derive::Deserialize;
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::{
     convert::TryInto,
     error,
     fs::{create_dir_all, read_to_string, File},
-    io::{self, sink, stdin, BufWriter, Read, Write},
+    io::{self, copy, sink, stdin, BufReader, BufWriter, Read, Write},
     mem,
+    ops::{Range, RangeInclusive},
     path::{Path, PathBuf},
     str::FromStr,
-    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
     thread::{sleep, spawn},
-    time::Duration,
+    time::{Duration, Instant},
 };
 use structopt::{
     clap::AppSettings::{NextLineHelp, UnifiedHelpMessage},
@@ -42,7 +47,7 @@ use structopt::{
 use xz2::write::XzEncoder;
 
 /// Arguments to the `dbgen` CLI program.
-#[derive(StructOpt, Debug, Deserialize)]
+#[derive(StructOpt, Clone, Debug, Deserialize, Serialize)]
 #[serde(default)]
 #[structopt(long_version(crate::FULL_VERSION), settings(&[NextLineHelp, UnifiedHelpMessage]))]
 pub struct Args {
@@ -82,10 +87,30 @@ pub struct Args {
     #[structopt(long)]
     pub last_insert_rows_count: Option<u32>,
 
+    /// Only generate the files with these indices (e.g. "17,42-50"), default to all files.
+    #[structopt(long, use_delimiter(true), parse(try_from_str = file_range_from_str))]
+    pub only_files: Vec<RangeInclusive<u32>>,
+
+    /// Only generate the rows with row numbers in this half-open range (e.g. "1001..2001").
+    #[structopt(long, parse(try_from_str = row_range_from_str))]
+    pub row_range: Option<Range<u64>>,
+
+    /// Limit the number of main rows written per second across all threads (e.g. "50000rows/s").
+    #[structopt(long, parse(try_from_str = rate_from_str))]
+    pub rate: Option<f64>,
+
+    /// Limit the number of bytes written per second across all threads (e.g. "100MiB/s").
+    #[structopt(long, parse(try_from_str = bandwidth_from_str))]
+    pub bandwidth: Option<f64>,
+
     /// Escape backslashes when writing a string.
     #[structopt(long)]
     pub escape_backslash: bool,
 
+    /// Write geographic values with the longitude-latitude axis order hint required by `MySQL`.
+    #[structopt(long)]
+    pub mysql_axis_order: bool,
+
     /// Generation template file.
     #[structopt(short = "i", long, parse(from_os_str))]
     pub template: PathBuf,
@@ -99,13 +124,17 @@ pub struct Args {
     pub jobs: usize,
 
     /// Random number generator engine
-    #[structopt(long, possible_values(&["chacha", "hc128", "isaac", "isaac64", "xorshift", "pcg32", "step"]), default_value = "hc128")]
+    #[structopt(long, possible_values(&["chacha", "hc128", "isaac", "isaac64", "xorshift", "pcg32", "step", "counter"]), default_value = "hc128")]
     pub rng: RngName,
 
     /// Disable progress bar.
     #[structopt(short, long)]
     pub quiet: bool,
 
+    /// How to report progress: a terminal progress bar ("bar"), or JSON lines on stderr ("json").
+    #[structopt(long, possible_values(&["bar", "json"]), default_value = "bar")]
+    pub progress: ProgressName,
+
     /// Time zone used for timestamps
     #[structopt(long, default_value = "UTC")]
     pub time_zone: Tz,
@@ -137,6 +166,10 @@ pub struct Args {
     /// Initializes the template with these global expressions.
     #[structopt(long, short = "D")]
     pub initialize: Vec<String>,
+
+    /// Write a manifest.json describing the generated files into the output directory.
+    #[structopt(long)]
+    pub manifest: bool,
 }
 
 /// The default implementation of the argument suitable for *testing*.
@@ -152,12 +185,18 @@ impl Default for Args {
             rows_count: 1,
             last_file_inserts_count: None,
             last_insert_rows_count: None,
+            only_files: Vec::new(),
+            row_range: None,
+            rate: None,
+            bandwidth: None,
             escape_backslash: false,
+            mysql_axis_order: false,
             template: PathBuf::default(),
             seed: None,
             jobs: 0,
             rng: RngName::Hc128,
             quiet: true,
+            progress: ProgressName::Bar,
             time_zone: Tz::UTC,
             now: None,
             format: FormatName::Sql,
@@ -166,6 +205,7 @@ impl Default for Args {
             no_schemas: false,
             no_data: false,
             initialize: Vec::new(),
+            manifest: false,
         }
     }
 }
@@ -190,6 +230,71 @@ fn now_from_str(s: &str) -> ParseResult<NaiveDateTime> {
     NaiveDateTime::parse_from_str(s, TIMESTAMP_FORMAT)
 }
 
+/// Parses a file index `17` or an inclusive range of file indices `42-50`.
+fn file_range_from_str(s: &str) -> Result<RangeInclusive<u32>, Error> {
+    let (start, end) = match s.find('-') {
+        Some(i) => (&s[..i], &s[(i + 1)..]),
+        None => (s, s),
+    };
+    let start = start.trim().parse::<u32>().context("invalid file index")?;
+    let end = end.trim().parse::<u32>().context("invalid file index")?;
+    if start == 0 || start > end {
+        bail!("invalid file index range {s}");
+    }
+    Ok(start..=end)
+}
+
+/// Parses a half-open range of row numbers `1001..2001`. The end can be omitted.
+fn row_range_from_str(s: &str) -> Result<Range<u64>, Error> {
+    let i = s.find("..").context("row range should be in the format START..END")?;
+    let start = s[..i].trim().parse::<u64>().context("invalid start row number")?;
+    let end = match s[(i + 2)..].trim() {
+        "" => u64::MAX,
+        end => end.parse::<u64>().context("invalid end row number")?,
+    };
+    if start == 0 || start > end {
+        bail!("invalid row range {s}");
+    }
+    Ok(start..end)
+}
+
+/// Parses a positive speed, after stripping the `suffix` and the optional `/s`.
+fn speed_from_str(s: &str, suffix: &str) -> Result<f64, Error> {
+    let s = s.trim();
+    let s = s.strip_suffix("/s").unwrap_or(s);
+    let s = s.strip_suffix(suffix).unwrap_or(s);
+    let speed = s.trim().parse::<f64>().context("invalid number")?;
+    if !(speed.is_finite() && speed > 0.0) {
+        bail!("speed must be positive");
+    }
+    Ok(speed)
+}
+
+/// Parses a row rate `50000rows/s` into number of rows per second.
+fn rate_from_str(s: &str) -> Result<f64, Error> {
+    speed_from_str(s, "rows")
+}
+
+/// Parses a bandwidth `100MiB/s` into number of bytes per second.
+fn bandwidth_from_str(s: &str) -> Result<f64, Error> {
+    const UNITS: &[(&str, f64)] = &[
+        ("KiB", 1024.0),
+        ("MiB", 1_048_576.0),
+        ("GiB", 1_073_741_824.0),
+        ("KB", 1e3),
+        ("MB", 1e6),
+        ("GB", 1e9),
+    ];
+    let s = s.trim();
+    let s = s.strip_suffix("/s").unwrap_or(s);
+    for (unit, multiplier) in UNITS {
+        if let Some(number) = s.strip_suffix(unit) {
+            return Ok(speed_from_str(number, "")? * multiplier);
+        }
+    }
+    speed_from_str(s, "B")
+}
+
 /// Extension trait for `Result` to annotate it with a file path.
 trait PathResultExt {
     type Ok;
@@ -222,7 +327,9 @@ fn read_template_file(path: &Path) -> Result<String, Error> {
 }
 
 /// Runs the CLI program.
+#[allow(clippy::too_many_lines)]
 pub fn run(args: Args) -> Result<(), Error> {
+    let manifest_args = if args.manifest { Some(args.clone()) } else { None };
     let input = read_template_file(&args.template)?;
     let mut template = Template::parse(&input, &args.initialize, args.schema_name.as_deref())?;
 
@@ -238,35 +345,66 @@ pub fn run(args: Args) -> Result<(), Error> {
         template.tables[0].name = QName::parse(override_table_name)?;
     }
 
+    if let Some(range) = args.only_files.iter().find(|r| *r.end() > args.files_count) {
+        bail!(
+            "file index range {}-{} in --only-files is beyond the number of files ({})",
+            range.start(),
+            range.end(),
+            args.files_count
+        );
+    }
+
+    let meta_seed = args.seed.unwrap_or_else(|| OsRng.gen());
     let mut ctx = CompileContext::new(template.variables_count);
+    ctx.seed = meta_seed;
+    ctx.template_dir = args.template.parent().map_or_else(PathBuf::new, Path::to_path_buf);
     ctx.time_zone = args.time_zone;
     ctx.current_timestamp = args.now.unwrap_or_else(|| Utc::now().naive_utc());
+    let current_timestamp = ctx.current_timestamp;
     let tables = template
         .tables
         .into_iter()
         .map(|t| ctx.compile_table(t))
-        .collect::<Result<_, _>>()?;
+        .collect::<Result<Vec<_>, _>>()?;
 
     create_dir_all(&args.out_dir).context("failed to create output directory")?;
 
+    let out_dir = args.out_dir.clone();
+    let table_names = tables
+        .iter()
+        .map(|t| t.name.table_name(true).to_owned())
+        .collect::<Vec<_>>();
+    let tables_rows_counters = tables.iter().map(|_| AtomicU64::new(0)).collect::<Arc<[_]>>();
+    let progress_table_rows = tables_rows_counters.clone();
     let compress_level = args.compress_level;
+    let row_range = args.row_range.unwrap_or(1..u64::MAX);
     let env = Env {
         out_dir: args.out_dir,
         file_num_digits: args.files_count.to_string().len(),
         tables,
         qualified: args.qualified,
         rows_count: args.rows_count,
+        row_range: row_range.clone(),
         escape_backslash: args.escape_backslash,
+        mysql_axis_order: args.mysql_axis_order,
         format: args.format,
         compression: args.compression.map(|c| (c, compress_level)),
         no_data: args.no_data,
+        manifest: args.manifest,
+        table_rows: tables_rows_counters,
+        throttle: if args.rate.is_some() || args.bandwidth.is_some() {
+            Some(Throttle::new(args.rate, args.bandwidth))
+        } else {
+            None
+        },
     };
 
-    if !args.no_schemas {
-        env.write_schema()?;
-    }
+    let mut manifest_files = if args.no_schemas {
+        Vec::new()
+    } else {
+        env.write_schema()?
+    };
 
-    let meta_seed = args.seed.unwrap_or_else(|| OsRng.gen());
     let show_progress = !args.quiet;
     if show_progress {
         println!("Using seed: {}", HEXLOWER_PERMISSIVE.encode(&meta_seed));
@@ -280,25 +418,18 @@ pub fn run(args: Args) -> Result<(), Error> {
     let mut rows_count = args.rows_count;
     let last_file_inserts_count = args.last_file_inserts_count.unwrap_or(inserts_count);
     let last_insert_rows_count = args.last_insert_rows_count.unwrap_or(rows_count);
+    let only_files = args.only_files;
 
     // Evaluate the global expressions if necessary.
     if !template.global_exprs.is_empty() {
         let row_gen = ctx.compile_row(template.global_exprs)?;
-        let mut state = State::new(0, rng_name.create(&mut seeding_rng), ctx);
+        let mut state = rng_name.create(&mut seeding_rng).into_state(0, ctx);
         row_gen.eval(&mut state)?;
         ctx = state.into_compile_context();
     }
 
-    let progress_bar_thread = spawn(move || {
-        if show_progress {
-            run_progress_thread(
-                u64::from(files_count - 1) * rows_per_file
-                    + u64::from(last_file_inserts_count - 1) * u64::from(rows_count)
-                    + u64::from(last_insert_rows_count),
-            );
-        }
-    });
-
+    // The RNGs of every file are always created, even if the file is skipped, so that the selected
+    // files are exactly the same as those in a full run.
     let iv = (0..files_count)
         .map(move |i| {
             let file_index = i + 1;
@@ -316,23 +447,135 @@ pub fn run(args: Args) -> Result<(), Error> {
                 u64::from(i) * rows_per_file + 1,
             )
         })
+        .filter(|(_, file_info, _)| {
+            only_files.is_empty() || only_files.iter().any(|r| r.contains(&file_info.file_index))
+        })
+        .map(|(seed, file_info, row_num)| {
+            let rows = row_num..row_num + file_info.rows_count(env.rows_count);
+            let selected_rows_count = rows
+                .end
+                .min(row_range.end)
+                .saturating_sub(rows.start.max(row_range.start));
+            ((seed, file_info, row_num), selected_rows_count)
+        })
+        .filter(|(_, selected_rows_count)| *selected_rows_count > 0)
         .collect::<Vec<_>>();
+    let total_rows = iv.iter().map(|(_, selected_rows_count)| selected_rows_count).sum();
+
+    let progress = args.progress;
+    let progress_bar_thread = spawn(move || {
+        if show_progress {
+            match progress {
+                ProgressName::Bar => run_progress_thread(total_rows),
+                ProgressName::Json => run_json_progress_thread(total_rows, &table_names, &progress_table_rows),
+            }
+        }
+    });
+
     let res = pool.install(move || {
-        iv.into_par_iter().try_for_each(|(seed, file_info, row_num)| {
-            let mut state = State::new(row_num, seed, ctx.clone());
-            env.write_data_file(&file_info, &mut state)
-        })
+        iv.into_par_iter()
+            .map(|((seed, file_info, row_num), _)| {
+                let mut state = seed.into_state(row_num, ctx.clone());
+                env.write_data_file(&file_info, &mut state)
+            })
+            .collect::<Result<Vec<_>, _>>()
     });
 
     WRITE_FINISHED.store(true, Ordering::Relaxed);
     progress_bar_thread.join().unwrap();
 
-    res?;
+    let data_files = res?;
+    if let Some(mut args) = manifest_args {
+        args.seed = Some(meta_seed);
+        args.now = Some(current_timestamp);
+        manifest_files.extend(data_files.into_iter().flatten());
+        let manifest = Manifest {
+            version: structopt::clap::crate_version!(),
+            seed: HEXLOWER_PERMISSIVE.encode(&meta_seed),
+            rng: rng_name,
+            args: &args,
+            files: manifest_files,
+        };
+        let path = out_dir.join(MANIFEST_FILE_NAME);
+        let file = BufWriter::new(File::create(&path).with_path(&path)?);
+        serde_json::to_writer_pretty(file, &manifest).with_path(&path)?;
+    }
     Ok(())
 }
 
+/// Name of the manifest file written into the output directory.
+const MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// The manifest describing the files generated in a run.
+#[derive(Serialize)]
+struct Manifest<'a> {
+    /// The version of `dbgen` which generated the files.
+    version: &'static str,
+    /// The seed in hex.
+    seed: String,
+    /// The random number generator.
+    rng: RngName,
+    /// The arguments with the seed and current timestamp filled in, which can be used to regenerate
+    /// the same files.
+    args: &'a Args,
+    /// The generated files.
+    files: Vec<ManifestFile>,
+}
+
+/// Kind of a generated file.
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum FileKind {
+    /// The `CREATE TABLE` schema file.
+    Schema,
+    /// The data file.
+    Data,
+}
+
+/// Information of a generated file recorded in the manifest.
+#[derive(Serialize)]
+struct ManifestFile {
+    /// Path of the file relative to the output directory.
+    path: String,
+    kind: FileKind,
+    /// Qualified name of the table.
+    table: String,
+    /// The 1-based file index, for data files only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_index: Option<u32>,
+    /// Number of rows written, for data files only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rows_count: Option<u64>,
+    /// Number of bytes before compression.
+    uncompressed_size: u64,
+    /// Number of bytes of the file on disk.
+    compressed_size: u64,
+    /// SHA-256 checksum of the file on disk in hex.
+    sha256: String,
+}
+
+impl ManifestFile {
+    /// Creates the manifest information of a generated file. The file is read back to compute the
+    /// checksum, so it must have been completely written.
+    fn new(path: &Path, kind: FileKind, table: &str, uncompressed_size: u64) -> Result<Self, Error> {
+        let mut hasher = Sha256::new();
+        let mut file = BufReader::new(File::open(path).with_path(path)?);
+        let compressed_size = copy(&mut file, &mut hasher).with_path(path)?;
+        Ok(Self {
+            path: path.file_name().unwrap_or_default().to_string_lossy().into_owned(),
+            kind,
+            table: table.to_owned(),
+            file_index: None,
+            rows_count: None,
+            uncompressed_size,
+            compressed_size,
+            sha256: HEXLOWER_PERMISSIVE.encode(&hasher.finalize()),
+        })
+    }
+}
+
 /// Names of random number generators supported by `dbgen`.
-#[derive(Copy, Clone, Debug, Deserialize)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 pub enum RngName {
     /// ChaCha20
     ChaCha,
@@ -348,6 +591,8 @@ pub enum RngName {
     Pcg32,
     /// Mock RNG which steps by a constant.
     Step,
+    /// Counter-based RNG, seeked by table, rownum, subrownum and column.
+    Counter,
 }
 
 impl FromStr for RngName {
@@ -361,6 +606,7 @@ impl FromStr for RngName {
             "xorshift" => Self::XorShift,
             "pcg32" => Self::Pcg32,
             "step" => Self::Step,
+            "counter" => Self::Counter,
             _ => bail!("Unsupported RNG {}", name),
         })
     }
@@ -368,8 +614,8 @@ impl FromStr for RngName {
 
 impl RngName {
     /// Creates an RNG engine given the name. The RNG engine instance will be seeded from `src`.
-    fn create(self, src: &mut StdRng) -> Box<dyn RngCore + Send> {
-        match self {
+    fn create(self, src: &mut StdRng) -> RngSeed {
+        RngSeed::Sequential(match self {
             Self::ChaCha => Box::new(rand_chacha::ChaChaRng::from_seed(src.gen())),
             Self::Hc128 => Box::new(rand_hc::Hc128Rng::from_seed(src.gen())),
             Self::Isaac => Box::new(rand_isaac::IsaacRng::from_seed(src.gen())),
@@ -377,12 +623,52 @@ impl RngName {
             Self::XorShift => Box::new(rand_xorshift::XorShiftRng::from_seed(src.gen())),
             Self::Pcg32 => Box::new(rand_pcg::Pcg32::from_seed(src.gen())),
             Self::Step => Box::new(StepRng::new(src.next_u64(), src.next_u64() | 1)),
+            // `src` is not advanced, so that every file shares the same key.
+            Self::Counter => return RngSeed::CounterBased(src.clone().gen()),
+        })
+    }
+}
+
+/// The seeded random number generator of a file.
+enum RngSeed {
+    /// A sequential RNG engine.
+    Sequential(Box<dyn RngCore + Send>),
+    /// The key of the counter-based RNG.
+    CounterBased([u8; 32]),
+}
+
+impl RngSeed {
+    /// Creates the evaluation state starting at `row_num` using this RNG.
+    fn into_state(self, row_num: u64, compile_context: CompileContext) -> State {
+        match self {
+            Self::Sequential(rng) => State::new(row_num, rng, compile_context),
+            Self::CounterBased(key) => State::new_counter_based(row_num, key, compile_context),
         }
     }
 }
 
+/// Styles of progress reporting supported by `dbgen`.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+pub enum ProgressName {
+    /// Terminal progress bar.
+    Bar,
+    /// JSON lines written to stderr.
+    Json,
+}
+
+impl FromStr for ProgressName {
+    type Err = Error;
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Ok(match name {
+            "bar" => Self::Bar,
+            "json" => Self::Json,
+            _ => bail!("Unsupported progress style {name}"),
+        })
+    }
+}
+
 /// Names of output formats supported by `dbgen`.
-#[derive(Copy, Clone, Debug, Deserialize)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 pub enum FormatName {
     /// SQL
     Sql,
@@ -411,16 +697,19 @@ impl FormatName {
     }
 
     /// Creates a formatter writer given the name.
-    fn create(self, escape_backslash: bool) -> Box<dyn Format> {
+    fn create(self, escape_backslash: bool, mysql_axis_order: bool) -> Box<dyn Format> {
         match self {
-            Self::Sql => Box::new(SqlFormat { escape_backslash }),
+            Self::Sql => Box::new(SqlFormat {
+                escape_backslash,
+                mysql_axis_order,
+            }),
             Self::Csv => Box::new(CsvFormat { escape_backslash }),
         }
     }
 }
 
 /// Names of the compression output formats supported by `dbgen`.
-#[derive(Copy, Clone, Debug, Deserialize)]
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
 pub enum CompressionName {
     /// Compress as gzip format (`*.gz`).
     Gzip,
@@ -470,17 +759,29 @@ impl CompressionName {
 struct WriteCountWrapper<W: Write> {
     inner: W,
     count: u64,
+    /// Number of bytes written, including those already committed.
+    total_count: u64,
 }
 impl<W: Write> WriteCountWrapper<W> {
     /// Creates a new [`WriteCountWrapper`] by wrapping another [`Write`].
     fn new(inner: W) -> Self {
-        Self { inner, count: 0 }
+        Self {
+            inner,
+            count: 0,
+            total_count: 0,
+        }
     }
 
     /// Commits the number of bytes written into the [`WRITTEN_SIZE`] global variable, and resets
     /// the byte count of all iterated `WriteCountWrapper` instances to zero.
     fn commit_bytes_written(instances: &mut [Self]) {
-        let total = instances.iter_mut().map(|w| mem::take(&mut w.count)).sum();
+        let total = instances
+            .iter_mut()
+            .map(|w| {
+                w.total_count += w.count;
+                mem::take(&mut w.count)
+            })
+            .sum();
         WRITTEN_SIZE.fetch_add(total, Ordering::Relaxed);
     }
 }
@@ -497,16 +798,98 @@ impl<W: Write> Write for WriteCountWrapper<W> {
 }
 
 /// The environmental data shared by all data writers.
+#[allow(clippy::struct_excessive_bools)]
 struct Env {
     out_dir: PathBuf,
     file_num_digits: usize,
     tables: Vec<Table>,
     qualified: bool,
     rows_count: u32,
+    row_range: Range<u64>,
     escape_backslash: bool,
+    mysql_axis_order: bool,
     format: FormatName,
     compression: Option<(CompressionName, u8)>,
     no_data: bool,
+    manifest: bool,
+    /// Counters of number of rows written for each table.
+    table_rows: Arc<[AtomicU64]>,
+    throttle: Option<Throttle>,
+}
+
+/// A token bucket limiting the growth of a counter to a steady rate.
+///
+/// The bucket holds at most one second worth of tokens, so after a stall the output can only burst
+/// briefly before returning to the steady rate.
+#[derive(Debug)]
+struct TokenBucket {
+    /// Number of tokens added per second.
+    rate: f64,
+    /// Number of tokens available. Becomes negative when more are consumed than available.
+    tokens: f64,
+    /// When the tokens were last refilled.
+    last_refill: Instant,
+    /// The value of the counter when tokens were last consumed.
+    last_count: u64,
+}
+
+impl TokenBucket {
+    /// Creates a full token bucket.
+    fn new(rate: f64, now: Instant) -> Self {
+        Self {
+            rate,
+            tokens: rate,
+            last_refill: now,
+            last_count: 0,
+        }
+    }
+
+    /// Consumes the tokens for the growth of the counter since last time, and returns how long to
+    /// wait until the bucket is no longer in debt.
+    #[allow(clippy::cast_precision_loss)]
+    fn consume(&mut self, count: u64, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate);
+        self.tokens -= count.saturating_sub(self.last_count) as f64;
+        self.last_refill = now;
+        self.last_count = self.last_count.max(count);
+        if self.tokens < 0.0 {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        } else {
+            Duration::from_secs(0)
+        }
+    }
+}
+
+/// Limits of the writing speed shared by all threads.
+struct Throttle {
+    rows: Option<Mutex<TokenBucket>>,
+    bytes: Option<Mutex<TokenBucket>>,
+}
+
+impl Throttle {
+    /// Creates the limits from the number of rows and bytes per second.
+    fn new(rows_per_second: Option<f64>, bytes_per_second: Option<f64>) -> Self {
+        let now = Instant::now();
+        Self {
+            rows: rows_per_second.map(|rate| Mutex::new(TokenBucket::new(rate, now))),
+            bytes: bytes_per_second.map(|rate| Mutex::new(TokenBucket::new(rate, now))),
+        }
+    }
+
+    /// Sleeps until the rows and bytes written so far by all threads are within the limits.
+    fn wait(&self) {
+        let consume = |bucket: &Option<Mutex<TokenBucket>>, counter: &AtomicU64| {
+            bucket.as_ref().map_or(Duration::from_secs(0), |bucket| {
+                let mut bucket = bucket.lock().unwrap_or_else(PoisonError::into_inner);
+                bucket.consume(counter.load(Ordering::Relaxed), Instant::now())
+            })
+        };
+        let delay = consume(&self.rows, &WRITE_PROGRESS).max(consume(&self.bytes, &WRITTEN_SIZE));
+        if delay > Duration::from_secs(0) {
+            sleep(delay);
+        }
+    }
 }
 
 /// Information specific to a file and its derived tables.
@@ -516,6 +899,13 @@ struct FileInfo {
     last_insert_rows_count: u32,
 }
 
+impl FileInfo {
+    /// Computes the number of main rows in this file.
+    fn rows_count(&self, rows_count: u32) -> u64 {
+        u64::from(self.inserts_count - 1) * u64::from(rows_count) + u64::from(self.last_insert_rows_count)
+    }
+}
+
 struct FileWriterEnv<'a> {
     env: &'a Env,
     state: &'a mut State,
@@ -526,11 +916,15 @@ struct FileWriterEnv<'a> {
     visited: Vec<bool>,
     /// For each INSERT statement, records number of rows included.
     actual_rows: Vec<u64>,
+    /// Records number of rows included in each file.
+    total_rows: Vec<u64>,
 }
 
 impl Env {
-    /// Writes the `CREATE TABLE` schema files.
-    fn write_schema(&self) -> Result<(), Error> {
+    /// Writes the `CREATE TABLE` schema files. Returns the manifest information of these files if
+    /// a manifest is requested.
+    fn write_schema(&self) -> Result<Vec<ManifestFile>, Error> {
+        let mut manifest_files = Vec::new();
         for table in &self.tables {
             let path = self.out_dir.join(format!("{}-schema.sql", table.name.unique_name()));
             let mut file = BufWriter::new(File::create(&path).with_path(&path)?);
@@ -541,8 +935,21 @@ impl Env {
                 table.content
             )
             .with_path(&path)?;
+            if self.manifest {
+                let file = file
+                    .into_inner()
+                    .map_err(io::IntoInnerError::into_error)
+                    .with_path(&path)?;
+                let size = file.metadata().with_path(&path)?.len();
+                manifest_files.push(ManifestFile::new(
+                    &path,
+                    FileKind::Schema,
+                    table.name.table_name(true),
+                    size,
+                )?);
+            }
         }
-        Ok(())
+        Ok(manifest_files)
     }
 
     fn open_data_file(&self, path: &mut PathBuf) -> Result<Box<dyn Write>, Error> {
@@ -559,15 +966,16 @@ impl Env {
         })
     }
 
-    /// Writes the data file.
-    fn write_data_file(&self, info: &FileInfo, state: &mut State) -> Result<(), Error> {
+    /// Writes the data file. Returns the manifest information of the written files if a manifest
+    /// is requested.
+    fn write_data_file(&self, info: &FileInfo, state: &mut State) -> Result<Vec<ManifestFile>, Error> {
         let path_suffix = format!(
             ".{0:01$}.{2}",
             info.file_index,
             self.file_num_digits,
             self.format.extension()
         );
-        let format = self.format.create(self.escape_backslash);
+        let format = self.format.create(self.escape_backslash, self.mysql_axis_order);
 
         let mut files = Vec::with_capacity(self.tables.len());
         let mut paths = Vec::with_capacity(self.tables.len());
@@ -586,6 +994,7 @@ impl Env {
             paths,
             visited: vec![false; self.tables.len()],
             actual_rows: vec![0; self.tables.len()],
+            total_rows: vec![0; self.tables.len()],
         };
 
         // for ((file, path), table) in files.iter_mut().zip(&self.tables) {
@@ -604,43 +1013,88 @@ impl Env {
             } else {
                 self.rows_count
             };
+            let mut written_rows_count = 0;
             for _ in 0..rows_count {
-                fwe.write_row()?;
+                // rows outside of the range are still evaluated to keep the RNG and variables in sync.
+                let should_write = self.row_range.contains(&fwe.state.row_num);
+                fwe.write_row(should_write)?;
+                written_rows_count += u64::from(should_write);
             }
             fwe.write_trailer()?;
 
             WriteCountWrapper::commit_bytes_written(&mut fwe.files);
-            WRITE_PROGRESS.fetch_add(rows_count.into(), Ordering::Relaxed);
+            WRITE_PROGRESS.fetch_add(written_rows_count, Ordering::Relaxed);
+            if let Some(throttle) = &self.throttle {
+                throttle.wait();
+            }
+
+            if fwe.state.row_num >= self.row_range.end {
+                break;
+            }
         }
-        Ok(())
+
+        if !self.manifest || self.no_data {
+            return Ok(Vec::new());
+        }
+
+        let FileWriterEnv {
+            files,
+            paths,
+            total_rows,
+            ..
+        } = fwe;
+        let mut manifest_files = Vec::with_capacity(files.len());
+        for (((file, path), rows_count), table) in files.into_iter().zip(paths).zip(total_rows).zip(&self.tables) {
+            let uncompressed_size = file.total_count;
+            // finish writing (and compressing) the file before computing the checksum.
+            file.inner
+                .into_inner()
+                .map_err(io::IntoInnerError::into_error)
+                .with_path(&path)?;
+            let mut manifest_file =
+                ManifestFile::new(&path, FileKind::Data, table.name.table_name(true), uncompressed_size)?;
+            manifest_file.file_index = Some(info.file_index);
+            manifest_file.rows_count = Some(rows_count);
+            manifest_files.push(manifest_file);
+        }
+        Ok(manifest_files)
     }
 }
 
 impl<'e> FileWriterEnv<'e> {
-    fn write_one_row(&mut self, i: usize) -> Result<(), Error> {
+    /// Evaluates one row of the table and its derived tables. The row is only written to the file
+    /// when `should_write` is true.
+    fn write_one_row(&mut self, i: usize, should_write: bool) -> Result<(), Error> {
         let file = &mut self.files[i];
         let table = &self.env.tables[i];
         let actual_rows = &mut self.actual_rows[i];
 
-        if self.state.sub_row_num == 1 && *actual_rows == 0 {
-            self.format
-                .write_header(file, table.name.table_name(self.env.qualified))
-        } else {
-            self.format.write_row_separator(file)
-        }?;
-
+        self.state.table_index = i;
         let values = table.row.eval(self.state)?;
-        *actual_rows += 1;
 
-        for (col_index, value) in values.iter().enumerate() {
-            if col_index != 0 {
-                self.format.write_value_separator(file)?;
+        if should_write {
+            if self.state.sub_row_num == 1 && *actual_rows == 0 {
+                self.format
+                    .write_header(file, table.name.table_name(self.env.qualified))
+            } else {
+                self.format.write_row_separator(file)
+            }?;
+
+            *actual_rows += 1;
+
+            for (col_index, value) in values.iter().enumerate() {
+                if col_index != 0 {
+                    self.format.write_value_separator(file)?;
+                }
+                self.format.write_value(file, value)?;
             }
-            self.format.write_value(file, value)?;
         }
 
-        for (child, count) in &table.derived {
+        for (j, (child, count)) in table.derived.iter().enumerate() {
+            self.state.table_index = i;
+            self.state.seek_rng(values.len() + j)?;
             let count = count.eval(self.state)?;
+            self.state.check_rng_overrun()?;
             let count: u64 = count.try_into().with_context(|| {
                 format!(
                     "number of rows to generate for {} is not an integer",
@@ -651,21 +1105,21 @@ impl<'e> FileWriterEnv<'e> {
             self.visited[*child] = true;
             for r in 1..=count {
                 self.state.sub_row_num = r;
-                self.write_one_row(*child)?;
+                self.write_one_row(*child, should_write)?;
             }
         }
 
         Ok(())
     }
 
-    fn write_row(&mut self) -> Result<(), Error> {
+    fn write_row(&mut self, should_write: bool) -> Result<(), Error> {
         for visited in &mut self.visited {
             *visited = false;
         }
         for i in 0..self.visited.len() {
             if !mem::replace(&mut self.visited[i], true) {
                 self.state.sub_row_num = 1;
-                self.write_one_row(i)?;
+                self.write_one_row(i, should_write)?;
             }
         }
         self.state.increase_row_num();
@@ -673,8 +1127,14 @@ impl<'e> FileWriterEnv<'e> {
     }
 
     fn write_trailer(&mut self) -> Result<(), Error> {
-        for ((file, path), actual_rows) in self.files.iter_mut().zip(&self.paths).zip(&mut self.actual_rows) {
-            if mem::take(actual_rows) > 0 {
+        let iter = self.files.iter_mut().zip(&self.paths).zip(&mut self.actual_rows);
+        for ((((file, path), actual_rows), total_rows), table_rows) in
+            iter.zip(&mut self.total_rows).zip(&*self.env.table_rows)
+        {
+            let rows_count = mem::take(actual_rows);
+            if rows_count > 0 {
+                *total_rows += rows_count;
+                table_rows.fetch_add(rows_count, Ordering::Relaxed);
                 self.format.write_trailer(file).with_path(path)?;
             }
         }
@@ -726,3 +1186,114 @@ fn run_progress_thread(total_rows: u64) {
 
     mb_thread.join().unwrap();
 }
+
+/// A progress report printed as a JSON line.
+#[derive(Serialize)]
+struct ProgressReport<'a> {
+    /// Either "progress" or "finished".
+    event: &'static str,
+    /// Number of seconds since the start of writing data files.
+    elapsed: f64,
+    /// Number of main rows written.
+    rows: u64,
+    /// Total number of main rows to be written.
+    total_rows: u64,
+    /// Number of bytes written (before compression).
+    bytes: u64,
+    rows_per_second: f64,
+    bytes_per_second: f64,
+    /// Estimated number of seconds until all rows are written.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eta: Option<f64>,
+    /// Number of rows written for each table, including derived tables.
+    tables: Vec<TableProgress<'a>>,
+}
+
+/// Number of rows written for a table in a [`ProgressReport`].
+#[derive(Serialize)]
+struct TableProgress<'a> {
+    table: &'a str,
+    rows: u64,
+}
+
+/// Runs the JSON progress thread.
+///
+/// This function will print a [`ProgressReport`] to stderr every second, until [`WRITE_FINISHED`]
+/// becomes `true`, and then print a final summary.
+#[allow(clippy::cast_precision_loss)]
+fn run_json_progress_thread(total_rows: u64, table_names: &[String], table_rows: &[AtomicU64]) {
+    let start = Instant::now();
+    let report = |event| {
+        let elapsed = start.elapsed().as_secs_f64();
+        let rows = WRITE_PROGRESS.load(Ordering::Relaxed);
+        let bytes = WRITTEN_SIZE.load(Ordering::Relaxed);
+        let rows_per_second = if elapsed > 0.0 { rows as f64 / elapsed } else { 0.0 };
+        let report = ProgressReport {
+            event,
+            elapsed,
+            rows,
+            total_rows,
+            bytes,
+            rows_per_second,
+            bytes_per_second: if elapsed > 0.0 { bytes as f64 / elapsed } else { 0.0 },
+            eta: if event == "progress" && rows_per_second > 0.0 {
+                Some(total_rows.saturating_sub(rows) as f64 / rows_per_second)
+            } else {
+                None
+            },
+            tables: table_names
+                .iter()
+                .zip(table_rows)
+                .map(|(table, rows)| TableProgress {
+                    table,
+                    rows: rows.load(Ordering::Relaxed),
+                })
+                .collect(),
+        };
+        if let Ok(line) = serde_json::to_string(&report) {
+            eprintln!("{line}");
+        }
+    };
+
+    let mut last_report = Instant::now();
+    while !WRITE_FINISHED.load(Ordering::Relaxed) {
+        sleep(Duration::from_millis(100));
+        if last_report.elapsed() >= Duration::from_secs(1) {
+            report("progress");
+            last_report = Instant::now();
+        }
+    }
+    report("finished");
+}
+
+#[test]
+fn test_speed_from_str() {
+    assert_eq!(rate_from_str("50000rows/s").unwrap(), 50000.0);
+    assert_eq!(rate_from_str(" 2.5 rows ").unwrap(), 2.5);
+    assert_eq!(rate_from_str("100").unwrap(), 100.0);
+    assert_eq!(bandwidth_from_str("100MiB/s").unwrap(), 104_857_600.0);
+    assert_eq!(bandwidth_from_str("1.5KB").unwrap(), 1500.0);
+    assert_eq!(bandwidth_from_str("3GiB/s").unwrap(), 3_221_225_472.0);
+    assert_eq!(bandwidth_from_str("512B/s").unwrap(), 512.0);
+    assert_eq!(bandwidth_from_str("512").unwrap(), 512.0);
+    assert!(rate_from_str("fast").is_err());
+    assert!(rate_from_str("0rows/s").is_err());
+    assert!(rate_from_str("-5").is_err());
+    assert!(rate_from_str("inf").is_err());
+    assert!(bandwidth_from_str("10TiB/s").is_err());
+    assert!(bandwidth_from_str("MiB/s").is_err());
+}
+
+#[test]
+fn test_token_bucket() {
+    let start = Instant::now();
+    let at = |ms| start + Duration::from_millis(ms);
+    let mut bucket = TokenBucket::new(100.0, start);
+    // a full bucket allows an initial burst of 1 second.
+    assert_eq!(bucket.consume(100, at(0)), Duration::from_secs(0));
+    // then it runs at the steady rate.
+    assert_eq!(bucket.consume(150, at(0)), Duration::from_millis(500));
+    assert_eq!(bucket.consume(150, at(500)), Duration::from_secs(0));
+    // after a long stall, the burst is still bounded by 1 second.
+    assert_eq!(bucket.consume(350, at(10_500)), Duration::from_secs(1));
+}
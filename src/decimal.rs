@@ -0,0 +1,310 @@
+//! Exact decimal numbers
+
+use std::{cmp::Ordering, convert::TryFrom, fmt, str::FromStr};
+
+/// The maximum number of digits after the decimal point, and also the maximum number of significant
+/// digits which can always be represented.
+pub const MAX_SCALE: u8 = 38;
+
+/// Computes 10<sup>n</sup> for n ≤ 38.
+fn pow10(n: u8) -> i128 {
+    10_i128.pow(n.into())
+}
+
+/// Divides two integers, rounding half away from zero.
+fn div_round(n: i128, d: i128) -> Option<i128> {
+    let q = n.checked_div(d)?;
+    let r = n % d;
+    if r.unsigned_abs() >= d.unsigned_abs() - r.unsigned_abs() {
+        q.checked_add(if (n < 0) == (d < 0) { 1 } else { -1 })
+    } else {
+        Some(q)
+    }
+}
+
+/// A fixed-point decimal number, stored as an integer mantissa and the number of digits after the
+/// decimal point. The value is *mantissa* × 10<sup>−*scale*</sup>.
+#[derive(Copy, Clone, Debug)]
+pub struct Decimal {
+    /// The mantissa.
+    mantissa: i128,
+    /// The number of digits after the decimal point.
+    scale: u8,
+}
+
+impl Decimal {
+    /// Creates a new decimal number with the given mantissa and scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the scale is greater than `MAX_SCALE`.
+    pub fn new(mantissa: i128, scale: u8) -> Self {
+        assert!(scale <= MAX_SCALE);
+        Self { mantissa, scale }
+    }
+
+    /// Gets the mantissa.
+    pub fn mantissa(self) -> i128 {
+        self.mantissa
+    }
+
+    /// Gets the number of digits after the decimal point.
+    pub fn scale(self) -> u8 {
+        self.scale
+    }
+
+    /// Converts a floating point number into a decimal, using the shortest representation which
+    /// round-trips to the same number.
+    pub fn from_f64(value: f64) -> Option<Self> {
+        if value.is_finite() {
+            value.to_string().parse().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Converts this number into the nearest floating point number.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_f64(self) -> f64 {
+        // formatting then parsing ensures correct rounding.
+        self.to_string()
+            .parse()
+            .unwrap_or(self.mantissa as f64 / 10_f64.powi(self.scale.into()))
+    }
+
+    /// Increases the scale without changing the value. Returns `None` on overflow.
+    fn upscale(self, scale: u8) -> Option<Self> {
+        debug_assert!(scale >= self.scale);
+        Some(Self {
+            mantissa: self.mantissa.checked_mul(pow10(scale - self.scale))?,
+            scale,
+        })
+    }
+
+    /// Converts both numbers to the same scale.
+    fn align(self, other: Self) -> Option<(i128, i128, u8)> {
+        let scale = self.scale.max(other.scale);
+        Some((self.upscale(scale)?.mantissa, other.upscale(scale)?.mantissa, scale))
+    }
+
+    /// Changes the scale of this number. When the scale is reduced, the discarded digits are
+    /// rounded towards an integer using the function `f`, which should be one of `f64::round`,
+    /// `f64::floor`, `f64::ceil` or `f64::trunc`. Returns `None` on overflow.
+    pub fn rescale(self, scale: u8, f: impl FnOnce(f64) -> f64) -> Option<Self> {
+        if scale >= self.scale {
+            return self.upscale(scale.min(MAX_SCALE));
+        }
+        let p = pow10(self.scale - scale);
+        let (q, r) = (self.mantissa / p, self.mantissa % p);
+        // pick a representative fraction so that `f` rounds exactly like the true fraction.
+        let fraction = match (2 * r.unsigned_abs()).cmp(&p.unsigned_abs()) {
+            _ if r == 0 => 0.0,
+            Ordering::Less => 0.25,
+            Ordering::Equal => 0.5,
+            Ordering::Greater => 0.75,
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let adjust = f(if r < 0 { -fraction } else { fraction }) as i128;
+        Some(Self {
+            mantissa: q.checked_add(adjust)?,
+            scale,
+        })
+    }
+
+    /// Adds two numbers. Returns `None` on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (a, b, scale) = self.align(other)?;
+        Some(Self::new(a.checked_add(b)?, scale))
+    }
+
+    /// Subtracts two numbers. Returns `None` on overflow.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (a, b, scale) = self.align(other)?;
+        Some(Self::new(a.checked_sub(b)?, scale))
+    }
+
+    /// Multiplies two numbers. Digits beyond `MAX_SCALE` are rounded. Returns `None` on overflow.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let mantissa = self.mantissa.checked_mul(other.mantissa)?;
+        let scale = self.scale + other.scale;
+        if scale > MAX_SCALE {
+            Some(Self::new(div_round(mantissa, pow10(scale - MAX_SCALE))?, MAX_SCALE))
+        } else {
+            Some(Self::new(mantissa, scale))
+        }
+    }
+
+    /// Divides two numbers. The quotient keeps 4 more digits after the decimal point than this number
+    /// (the default `div_precision_increment`), rounding half away from zero. Returns `None` on
+    /// overflow or division by zero.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let scale = (self.scale + 4).min(MAX_SCALE);
+        // self / other × 10^scale = (m1 × 10^(scale - s1 + s2)) / m2
+        let shift = i32::from(scale) - i32::from(self.scale) + i32::from(other.scale);
+        let (n, d) = if shift >= 0 {
+            (
+                self.mantissa.checked_mul(10_i128.checked_pow(shift.unsigned_abs())?)?,
+                other.mantissa,
+            )
+        } else {
+            (
+                self.mantissa,
+                other.mantissa.checked_mul(pow10(u8::try_from(-shift).ok()?))?,
+            )
+        };
+        Some(Self::new(div_round(n, d)?, scale))
+    }
+
+    /// Divides two numbers, truncating towards zero into an integer. Returns `None` on overflow or
+    /// division by zero.
+    pub fn checked_div_integral(self, other: Self) -> Option<i128> {
+        let (a, b, _) = self.align(other)?;
+        a.checked_div(b)
+    }
+
+    /// Computes the remainder when dividing two numbers. Returns `None` on overflow or division by
+    /// zero.
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        let (a, b, scale) = self.align(other)?;
+        Some(Self::new(a.checked_rem(b)?, scale))
+    }
+
+    /// Computes the absolute value. Returns `None` on overflow.
+    pub fn checked_abs(self) -> Option<Self> {
+        Some(Self::new(self.mantissa.checked_abs()?, self.scale))
+    }
+
+    /// Negates this number, wrapping on overflow.
+    #[must_use]
+    pub fn wrapping_neg(self) -> Self {
+        Self::new(self.mantissa.wrapping_neg(), self.scale)
+    }
+}
+
+impl From<i128> for Decimal {
+    fn from(value: i128) -> Self {
+        Self::new(value, 0)
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let Some((a, b, _)) = self.align(*other) {
+            return a.cmp(&b);
+        }
+        // aligning overflowed, so compare the integral and fractional parts separately.
+        let split = |d: &Self| {
+            let p = pow10(d.scale);
+            (
+                d.mantissa.div_euclid(p),
+                d.mantissa.rem_euclid(p) * pow10(MAX_SCALE - d.scale),
+            )
+        };
+        split(self).cmp(&split(other))
+    }
+}
+
+/// Displays the number in plain notation with exactly *scale* digits after the decimal point.
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let scale = usize::from(self.scale);
+        if self.mantissa < 0 {
+            f.write_str("-")?;
+        }
+        if scale == 0 {
+            return f.write_str(&digits);
+        }
+        if digits.len() > scale {
+            let (integral, fraction) = digits.split_at(digits.len() - scale);
+            write!(f, "{integral}.{fraction}")
+        } else {
+            write!(f, "0.{digits:0>scale$}")
+        }
+    }
+}
+
+/// The error returned when parsing an invalid decimal number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDecimalError;
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid decimal number")
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+/// Parses a decimal number in plain notation like `-123.4500`. The scale is the number of digits
+/// written after the decimal point.
+impl FromStr for Decimal {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (is_negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (integral, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if integral.len() + fraction.len() == 0 || !is_digits(integral) || !is_digits(fraction) {
+            return Err(ParseDecimalError);
+        }
+        let scale = u8::try_from(fraction.len()).map_err(|_| ParseDecimalError)?;
+        if scale > MAX_SCALE {
+            return Err(ParseDecimalError);
+        }
+        let mut mantissa = 0_i128;
+        for b in integral.bytes().chain(fraction.bytes()) {
+            let digit = i128::from(b - b'0');
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| {
+                    if is_negative {
+                        m.checked_sub(digit)
+                    } else {
+                        m.checked_add(digit)
+                    }
+                })
+                .ok_or(ParseDecimalError)?;
+        }
+        Ok(Self::new(mantissa, scale))
+    }
+}
+
+#[test]
+fn test_decimal_arithmetic() {
+    let d = |s: &str| s.parse::<Decimal>().unwrap();
+    assert_eq!(d("0.1").checked_add(d("0.2")).unwrap().to_string(), "0.3");
+    assert_eq!(d("1.50").checked_sub(d("3")).unwrap().to_string(), "-1.50");
+    assert_eq!(d("-0.05").checked_mul(d("0.5")).unwrap().to_string(), "-0.025");
+    assert_eq!(d("1").checked_div(d("3")).unwrap().to_string(), "0.3333");
+    assert_eq!(d("2.00").checked_div(d("0.3")).unwrap().to_string(), "6.666667");
+    assert_eq!(d("-7.5").checked_rem(d("2")).unwrap().to_string(), "-1.5");
+    assert_eq!(d("1").checked_div(d("0")), None);
+    assert_eq!(d("2.345").rescale(2, f64::round).unwrap().to_string(), "2.35");
+    assert_eq!(d("-2.345").rescale(2, f64::round).unwrap().to_string(), "-2.35");
+    assert_eq!(d("-2.345").rescale(0, f64::floor).unwrap().to_string(), "-3");
+    assert_eq!(d("2.3").rescale(3, f64::round).unwrap().to_string(), "2.300");
+    assert_eq!(Decimal::from_f64(1e-7).unwrap().to_string(), "0.0000001");
+    assert!(d("0.30") == d("0.3"));
+    assert!(d("-1") < d("0.00000000000000000000000000000000000001"));
+    assert!("1e5".parse::<Decimal>().is_err());
+    assert!(".".parse::<Decimal>().is_err());
+}
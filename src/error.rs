@@ -99,4 +99,15 @@ pub enum Error {
         /// The table name in the CREATE TABLE statement
         create_table: String,
     },
+
+    /// A position exceeds the range reserved for it by the counter-based RNG.
+    #[error("{field} ({value}) exceeds the maximum ({max}) supported by the counter-based RNG")]
+    CounterRngOverflow {
+        /// The name of the position.
+        field: &'static str,
+        /// The actual position.
+        value: u128,
+        /// The maximum supported position.
+        max: u128,
+    },
 }
@@ -1,19 +1,38 @@
 //! Evaluating compiled expressions into values.
 
 use crate::{
+    decimal::Decimal,
     error::Error,
-    functions::{Arguments, Function},
+    functions::{
+        fake::FakeData,
+        geo::GeometryGenerator,
+        rand::{
+            format_mac, CompressiblePayload, GeometricDistribution, HistogramDistribution, MultivariateNormal,
+            TimeOrderedId,
+        },
+        text::{LoremGenerator, MarkovChain},
+        Arguments, Function,
+    },
     parser::{Expr, QName},
     value::Value,
 };
 use chrono::NaiveDateTime;
 use chrono_tz::Tz;
-use rand::{distributions::Bernoulli, seq::SliceRandom, Rng, RngCore};
-use rand_distr::{LogNormal, Uniform};
+use rand::{
+    distributions::{weighted::alias_method::WeightedIndex, Bernoulli},
+    seq::{index::sample, SliceRandom},
+    Rng, RngCore, SeedableRng,
+};
+use rand_chacha::ChaChaRng;
+use rand_distr::{
+    Beta, Binomial, Cauchy, Exp, Gamma, LogNormal, Normal, Pareto, Poisson, Triangular, Uniform, Weibull,
+};
 use std::{
     cmp::Ordering,
     convert::{TryFrom, TryInto},
     fmt,
+    net::{Ipv4Addr, Ipv6Addr},
+    path::PathBuf,
     sync::Arc,
 };
 use zipf::ZipfDistribution;
@@ -27,6 +46,12 @@ pub struct CompileContext {
     pub current_timestamp: NaiveDateTime,
     /// The global variables.
     pub variables: Box<[Value]>,
+    /// The template seed. Functions like `rand.permute` derive their keys from it, so their results
+    /// do not depend on how the rows are split into files.
+    pub seed: [u8; 32],
+    /// The directory containing the template file. Relative paths in functions like `load_csv` are
+    /// resolved against this directory.
+    pub template_dir: PathBuf,
 }
 
 impl CompileContext {
@@ -36,6 +61,8 @@ impl CompileContext {
             time_zone: Tz::UTC,
             current_timestamp: NaiveDateTime::from_timestamp(0, 0),
             variables: vec![Value::Null; variables_count].into_boxed_slice(),
+            seed: [0; 32],
+            template_dir: PathBuf::new(),
         }
     }
 }
@@ -45,7 +72,9 @@ pub struct State {
     pub(crate) row_num: u64,
     /// Defines the value of `subrownum`.
     pub sub_row_num: u64,
-    rng: Box<dyn RngCore>,
+    /// The index of the table being evaluated.
+    pub(crate) table_index: usize,
+    rng: StateRng,
     compile_context: CompileContext,
 }
 
@@ -54,6 +83,7 @@ impl fmt::Debug for State {
         f.debug_struct("State")
             .field("row_num", &self.row_num)
             .field("sub_row_num", &self.sub_row_num)
+            .field("table_index", &self.table_index)
             .field("rng", &())
             .field("variables", &self.compile_context.variables)
             .finish()
@@ -72,7 +102,26 @@ impl State {
         Self {
             row_num,
             sub_row_num: 1,
-            rng,
+            table_index: 0,
+            rng: StateRng::Sequential(rng),
+            compile_context,
+        }
+    }
+
+    /// Creates a new state using a counter-based random number generator.
+    ///
+    /// The random values drawn by each column depend only on the `key`, the table index, `rownum`,
+    /// `subrownum` and the column index, so every row can be regenerated independently of the rows
+    /// before it.
+    pub fn new_counter_based(row_num: u64, key: [u8; 32], compile_context: CompileContext) -> Self {
+        Self {
+            row_num,
+            sub_row_num: 1,
+            table_index: 0,
+            rng: StateRng::CounterBased {
+                rng: Box::new(ChaChaRng::from_seed(key)),
+                column_start: 0,
+            },
             compile_context,
         }
     }
@@ -86,6 +135,90 @@ impl State {
     pub fn increase_row_num(&mut self) {
         self.row_num += 1;
     }
+
+    /// Moves the counter-based RNG to the position reserved for the given column of the current
+    /// row. Does nothing for sequential RNGs.
+    ///
+    /// Returns an error if the table index, column index or subrownum cannot fit in the reserved
+    /// bits, since the random values would then repeat those of another column.
+    pub(crate) fn seek_rng(&mut self, column_index: usize) -> Result<(), Error> {
+        if let StateRng::CounterBased { rng, column_start } = &mut self.rng {
+            // The 68-bit word position is divided into 12 bits of table index, 12 bits of column
+            // index, 24 bits of subrownum and 20 bits for the words drawn by the column.
+            let word_pos = counter_rng_field("table index", self.table_index as u128, 12)? << 56
+                | counter_rng_field("column index", column_index as u128, 12)? << 44
+                | counter_rng_field("subrownum", self.sub_row_num.into(), 24)? << 20;
+            rng.set_stream(self.row_num);
+            rng.set_word_pos(word_pos);
+            *column_start = word_pos;
+        }
+        Ok(())
+    }
+
+    /// Checks that the current column did not draw more words from the counter-based RNG than
+    /// the space reserved by `seek_rng`. Does nothing for sequential RNGs.
+    pub(crate) fn check_rng_overrun(&self) -> Result<(), Error> {
+        if let StateRng::CounterBased { rng, column_start } = &self.rng {
+            counter_rng_field(
+                "number of random words drawn by a column",
+                rng.get_word_pos() - column_start,
+                20,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that a position of the counter-based RNG fits in the given number of bits.
+fn counter_rng_field(field: &'static str, value: u128, bits: u32) -> Result<u128, Error> {
+    let max = (1 << bits) - 1;
+    if value > max {
+        return Err(Error::CounterRngOverflow { field, value, max });
+    }
+    Ok(value)
+}
+
+/// The random number generator of a [`State`].
+enum StateRng {
+    /// An RNG producing a single sequence for the whole file.
+    Sequential(Box<dyn RngCore>),
+    /// A counter-based RNG which is seeked to a distinct position for every column.
+    CounterBased {
+        /// The RNG.
+        rng: Box<ChaChaRng>,
+        /// The word position where the current column started.
+        column_start: u128,
+    },
+}
+
+impl RngCore for StateRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Self::Sequential(rng) => rng.next_u32(),
+            Self::CounterBased { rng, .. } => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::Sequential(rng) => rng.next_u64(),
+            Self::CounterBased { rng, .. } => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Self::Sequential(rng) => rng.fill_bytes(dest),
+            Self::CounterBased { rng, .. } => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            Self::Sequential(rng) => rng.try_fill_bytes(dest),
+            Self::CounterBased { rng, .. } => rng.try_fill_bytes(dest),
+        }
+    }
 }
 
 /// A compiled table
@@ -135,8 +268,10 @@ impl Row {
     /// Evaluates the row into a vector of values.
     pub fn eval(&self, state: &mut State) -> Result<Vec<Value>, Error> {
         let mut result = Vec::with_capacity(self.0.len());
-        for compiled in &self.0 {
+        for (column_index, compiled) in self.0.iter().enumerate() {
+            state.seek_rng(column_index)?;
             result.push(compiled.eval(state)?);
+            state.check_rng_overrun()?;
         }
         Ok(result)
     }
@@ -184,6 +319,28 @@ pub(crate) enum C {
     RandZipf(ZipfDistribution),
     /// Log-normal distribution.
     RandLogNormal(LogNormal<f64>),
+    /// Normal distribution.
+    RandNormal(Normal<f64>),
+    /// Exponential distribution.
+    RandExp(Exp<f64>),
+    /// Poisson distribution.
+    RandPoisson(Poisson<f64>),
+    /// Binomial distribution.
+    RandBinomial(Binomial),
+    /// Geometric distribution.
+    RandGeometric(GeometricDistribution),
+    /// Gamma distribution.
+    RandGamma(Gamma<f64>),
+    /// Beta distribution.
+    RandBeta(Box<Beta<f64>>),
+    /// Weibull distribution.
+    RandWeibull(Weibull<f64>),
+    /// Pareto distribution.
+    RandPareto(Pareto<f64>),
+    /// Triangular distribution.
+    RandTriangular(Triangular<f64>),
+    /// Cauchy distribution.
+    RandCauchy(Cauchy<f64>),
     /// Bernoulli distribution for `bool` (i.e. a weighted random boolean).
     RandBool(Bernoulli),
     /// Random f32 with uniform bit pattern
@@ -192,10 +349,76 @@ pub(crate) enum C {
     RandFiniteF64(Uniform<u64>),
     /// Random u31 timestamp
     RandU31Timestamp(Uniform<i64>),
+    /// Random decimal number
+    RandDecimal {
+        /// Distribution of the mantissa.
+        mantissa: Uniform<i128>,
+        /// Number of digits after the decimal point.
+        scale: u8,
+    },
+    /// Random payload with a target compression ratio
+    RandCompressible(CompressiblePayload),
     /// Random shuffled array
     RandShuffle(Arc<[Value]>),
+    /// Random element of an array
+    RandChoice {
+        /// The array to choose from.
+        array: Arc<[Value]>,
+        /// Uniform distribution of the array indices.
+        index: Uniform<usize>,
+    },
+    /// Random element of an array with custom weights
+    RandWeighted {
+        /// The array to choose from.
+        array: Arc<[Value]>,
+        /// Alias table of the array indices.
+        index: Box<WeightedIndex<f64>>,
+    },
+    /// Piecewise uniform distribution from a histogram
+    RandHistogram(Box<HistogramDistribution>),
+    /// Multivariate normal distribution, or Gaussian copula
+    RandMvNormal(Box<MultivariateNormal>),
+    /// Random subset of an array, without replacement
+    RandSample {
+        /// The array to sample from.
+        array: Arc<[Value]>,
+        /// Number of elements to take.
+        amount: usize,
+    },
     /// Random (version 4) UUID
     RandUuid,
+    /// Random IPv4 address
+    RandIpv4 {
+        /// Range of addresses as 32-bit integers.
+        range: Uniform<u32>,
+        /// Whether to produce the 4-byte binary form instead of the dotted string.
+        binary: bool,
+    },
+    /// Random IPv6 address
+    RandIpv6 {
+        /// Whether to produce the 16-byte binary form instead of the colon-separated string.
+        binary: bool,
+    },
+    /// Random unicast MAC address
+    RandMac {
+        /// Whether to produce the 6-byte binary form instead of the colon-separated string.
+        binary: bool,
+    },
+    /// Lorem ipsum text
+    RandLorem(LoremGenerator),
+    /// Text generated from a Markov chain
+    RandMarkov {
+        /// The Markov chain model.
+        chain: Arc<MarkovChain>,
+        /// Maximum number of characters to generate.
+        max_len: usize,
+    },
+    /// Random geometry
+    RandGeometry(GeometryGenerator),
+    /// Fake data from the embedded word lists
+    RandFake(FakeData),
+    /// Random version 7 UUID, ULID or KSUID with the given timestamp
+    RandTimeOrderedId(TimeOrderedId),
 }
 
 /// A compiled expression
@@ -274,6 +497,7 @@ impl Compiled {
     }
 
     /// Evaluates a compiled expression and updates the state. Returns the evaluated value.
+    #[allow(clippy::too_many_lines)]
     pub fn eval(&self, state: &mut State) -> Result<Value, Error> {
         Ok(match &self.0 {
             C::RowNum => state.row_num.into(),
@@ -334,22 +558,47 @@ impl Compiled {
             C::RandUniformF64(uniform) => state.rng.sample(uniform).into(),
             C::RandZipf(zipf) => (state.rng.sample(zipf) as u64).into(),
             C::RandLogNormal(log_normal) => state.rng.sample(log_normal).into(),
+            C::RandNormal(normal) => state.rng.sample(normal).into(),
+            C::RandExp(exp) => state.rng.sample(exp).into(),
+            C::RandPoisson(poisson) => state.rng.sample::<u64, _>(poisson).into(),
+            C::RandBinomial(binomial) => state.rng.sample(binomial).into(),
+            C::RandGeometric(geometric) => state.rng.sample(geometric).into(),
+            C::RandGamma(gamma) => state.rng.sample(gamma).into(),
+            C::RandBeta(beta) => state.rng.sample(beta.as_ref()).into(),
+            C::RandWeibull(weibull) => state.rng.sample(weibull).into(),
+            C::RandPareto(pareto) => state.rng.sample(pareto).into(),
+            C::RandTriangular(triangular) => state.rng.sample(triangular).into(),
+            C::RandCauchy(cauchy) => state.rng.sample(cauchy).into(),
             C::RandBool(bern) => u64::from(state.rng.sample(bern)).into(),
             C::RandFiniteF32(uniform) => f32::from_bits(state.rng.sample(uniform).rotate_right(1)).into(),
             C::RandFiniteF64(uniform) => f64::from_bits(state.rng.sample(uniform).rotate_right(1)).into(),
 
+            C::RandDecimal { mantissa, scale } => Decimal::new(state.rng.sample(mantissa), *scale).into(),
             C::RandU31Timestamp(uniform) => {
                 let seconds = state.rng.sample(uniform);
                 let timestamp = NaiveDateTime::from_timestamp(seconds, 0);
                 Value::new_timestamp(timestamp, state.compile_context.time_zone)
             }
 
+            C::RandCompressible(payload) => payload.sample(&mut state.rng),
             C::RandShuffle(array) => {
                 let mut shuffled_array = Arc::<[Value]>::from(&**array);
                 Arc::get_mut(&mut shuffled_array).unwrap().shuffle(&mut state.rng);
                 Value::Array(shuffled_array)
             }
 
+            C::RandChoice { array, index } => array[state.rng.sample(index)].clone(),
+            C::RandWeighted { array, index } => array[state.rng.sample(&**index)].clone(),
+            C::RandHistogram(histogram) => state.rng.sample(&**histogram).into(),
+            C::RandMvNormal(mv_normal) => {
+                let vector: Vec<f64> = state.rng.sample(&**mv_normal);
+                Value::Array(vector.into_iter().map(Value::from).collect())
+            }
+            C::RandSample { array, amount } => {
+                let indices = sample(&mut state.rng, array.len(), *amount);
+                Value::Array(indices.into_iter().map(|i| array[i].clone()).collect())
+            }
+
             C::RandUuid => {
                 // we will loss 6 bits but that's still uniform.
                 let g = state.rng.gen::<[u16; 8]>();
@@ -366,6 +615,37 @@ impl Compiled {
                 )
                 .into()
             }
+            C::RandIpv4 { range, binary } => {
+                let addr = state.rng.sample(range);
+                if *binary {
+                    Value::new_binary(addr.to_be_bytes().to_vec())
+                } else {
+                    Ipv4Addr::from(addr).to_string().into()
+                }
+            }
+            C::RandIpv6 { binary } => {
+                let addr = state.rng.gen::<u128>();
+                if *binary {
+                    Value::new_binary(addr.to_be_bytes().to_vec())
+                } else {
+                    Ipv6Addr::from(addr).to_string().into()
+                }
+            }
+            C::RandMac { binary } => {
+                let mut mac = state.rng.gen::<[u8; 6]>();
+                // clear the multicast bit.
+                mac[0] &= !1;
+                if *binary {
+                    Value::new_binary(mac.to_vec())
+                } else {
+                    format_mac(mac).into()
+                }
+            }
+            C::RandLorem(lorem) => lorem.sample(&mut state.rng).into(),
+            C::RandMarkov { chain, max_len } => chain.sample(&mut state.rng, *max_len).into(),
+            C::RandGeometry(generator) => generator.sample(&mut state.rng).into(),
+            C::RandFake(fake) => fake.sample(&mut state.rng).into(),
+            C::RandTimeOrderedId(id) => id.sample(&mut state.rng).into(),
         })
     }
 }
@@ -2,7 +2,7 @@
 
 use crate::value::{Bytes, Value};
 
-use chrono::{DateTime, Datelike, TimeZone, Timelike};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, TimeZone, Timelike};
 use chrono_tz::Tz;
 use memchr::{memchr2_iter, memchr3_iter, memchr_iter};
 use std::{
@@ -34,6 +34,8 @@ pub trait Format {
 pub struct SqlFormat {
     /// Whether to escapes backslashes when writing a string.
     pub escape_backslash: bool,
+    /// Whether to write geographic values with the `MySQL` longitude-latitude axis order hint.
+    pub mysql_axis_order: bool,
 }
 
 /// CSV formatter.
@@ -63,6 +65,36 @@ fn write_timestamp(writer: &mut dyn Write, quote: &str, timestamp: &DateTime<Tz>
     writer.write_all(quote.as_bytes())
 }
 
+/// Writes a date in ISO 8601 format.
+fn write_date(writer: &mut dyn Write, quote: &str, date: NaiveDate) -> Result<(), Error> {
+    write!(
+        writer,
+        "{}{:04}-{:02}-{:02}{}",
+        quote,
+        date.year(),
+        date.month(),
+        date.day(),
+        quote
+    )
+}
+
+/// Writes a time of day in ISO 8601 format.
+fn write_time(writer: &mut dyn Write, quote: &str, time: NaiveTime) -> Result<(), Error> {
+    write!(
+        writer,
+        "{}{:02}:{:02}:{:02}",
+        quote,
+        time.hour(),
+        time.minute(),
+        time.second()
+    )?;
+    let ns = time.nanosecond();
+    if ns != 0 {
+        write!(writer, ".{:06}", ns / 1000)?;
+    }
+    writer.write_all(quote.as_bytes())
+}
+
 /// Writes a time interval in the standard SQL format.
 fn write_interval(writer: &mut dyn Write, quote: &str, mut interval: i64) -> Result<(), Error> {
     writer.write_all(quote.as_bytes())?;
@@ -147,18 +179,23 @@ impl SqlFormat {
             for b in bytes.as_bytes() {
                 write!(writer, "{:02X}", b)?;
             }
+            writer.write_all(b"'")
         } else {
-            writer.write_all(b"'")?;
-            write_with_escape(
-                writer,
-                bytes.as_bytes(),
-                if self.escape_backslash {
-                    &[(b'\'', b"''"), (b'\\', br"\\"), (b'\0', br"\0")]
-                } else {
-                    &[(b'\'', b"''")]
-                },
-            )?;
+            self.write_text(writer, bytes.as_bytes())
         }
+    }
+
+    fn write_text(&self, writer: &mut dyn Write, text: &[u8]) -> Result<(), Error> {
+        writer.write_all(b"'")?;
+        write_with_escape(
+            writer,
+            text,
+            if self.escape_backslash {
+                &[(b'\'', b"''"), (b'\\', br"\\"), (b'\0', br"\0")]
+            } else {
+                &[(b'\'', b"''")]
+            },
+        )?;
         writer.write_all(b"'")
     }
 }
@@ -170,6 +207,8 @@ impl Format for SqlFormat {
             Value::Number(number) => write!(writer, "{}", number),
             Value::Bytes(bytes) => self.write_bytes(writer, bytes),
             Value::Timestamp(timestamp, tz) => write_timestamp(writer, "'", &tz.from_utc_datetime(timestamp)),
+            Value::Date(date) => write_date(writer, "'", *date),
+            Value::Time(time) => write_time(writer, "'", *time),
             Value::Interval(interval) => write_interval(writer, "'", *interval),
             Value::Array(array) => {
                 writer.write_all(b"ARRAY[")?;
@@ -181,6 +220,8 @@ impl Format for SqlFormat {
                 }
                 writer.write_all(b"]")
             }
+            Value::Geometry(geometry) => geometry.write_sql(writer, self.mysql_axis_order),
+            Value::Json(json) => self.write_text(writer, json.to_string().as_bytes()),
         }
     }
 
@@ -202,11 +243,11 @@ impl Format for SqlFormat {
 }
 
 impl CsvFormat {
-    fn write_bytes(&self, writer: &mut dyn Write, bytes: &Bytes) -> Result<(), Error> {
+    fn write_text(&self, writer: &mut dyn Write, text: &[u8]) -> Result<(), Error> {
         writer.write_all(b"\"")?;
         write_with_escape(
             writer,
-            bytes.as_bytes(),
+            text,
             if self.escape_backslash {
                 &[(b'"', b"\"\""), (b'\\', br"\\")]
             } else {
@@ -222,8 +263,10 @@ impl Format for CsvFormat {
         match value {
             Value::Null => writer.write_all(br"\N"),
             Value::Number(number) => write!(writer, "{}", number),
-            Value::Bytes(bytes) => self.write_bytes(writer, bytes),
+            Value::Bytes(bytes) => self.write_text(writer, bytes.as_bytes()),
             Value::Timestamp(timestamp, tz) => write_timestamp(writer, "", &tz.from_utc_datetime(timestamp)),
+            Value::Date(date) => write_date(writer, "", *date),
+            Value::Time(time) => write_time(writer, "", *time),
             Value::Interval(interval) => write_interval(writer, "", *interval),
             Value::Array(array) => {
                 writer.write_all(b"{")?;
@@ -235,6 +278,8 @@ impl Format for CsvFormat {
                 }
                 writer.write_all(b"}")
             }
+            Value::Geometry(geometry) => write!(writer, "\"{geometry}\""),
+            Value::Json(json) => self.write_text(writer, json.to_string().as_bytes()),
         }
     }
 
@@ -38,7 +38,7 @@ impl Function for Subscript {
 pub struct GenerateSeries;
 
 impl Function for GenerateSeries {
-    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
         let name = "generate_series";
         let (start, end, step) = args_3::<Value, Value, Value>(name, args, None, None, Some(1.into()))?;
 
@@ -57,7 +57,7 @@ impl Function for GenerateSeries {
             if cur_cmp.is_none() || cur_cmp == Some(step_sign) {
                 break;
             }
-            let next = value.sql_add_named(&step, name)?;
+            let next = value.sql_add_named(&step, ctx.time_zone, name)?;
             result.push(value);
             value = next;
         }
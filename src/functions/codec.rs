@@ -0,0 +1,321 @@
+//! Hash and encoding functions.
+
+use super::{args_1, args_2, Arguments, Function};
+use crate::{
+    error::Error,
+    eval::{CompileContext, Compiled, C},
+    value::{Bytes, Value},
+};
+use data_encoding::{BASE64, HEXLOWER, HEXLOWER_PERMISSIVE, HEXUPPER};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest as _, Sha256, Sha512};
+use std::{convert::TryFrom, hash::Hasher, iter::once, net::Ipv4Addr, str::from_utf8};
+use twox_hash::XxHash64;
+
+/// Converts a value into its canonical string form for hashing.
+///
+/// Returns `None` if the value is NULL.
+fn canonical_bytes(value: Value) -> Result<Option<Vec<u8>>, Error> {
+    Ok(Vec::try_from(Value::sql_concat(once(value))?).ok())
+}
+
+//------------------------------------------------------------------------------
+
+/// Computes the 64-bit xxHash of the input.
+fn xxh64(input: &[u8], seed: u64) -> u64 {
+    let mut hasher = XxHash64::with_seed(seed);
+    hasher.write(input);
+    hasher.finish()
+}
+
+#[test]
+fn test_xxh64() {
+    assert_eq!(xxh64(b"", 0), 0xef46_db37_51d8_e999);
+    assert_eq!(xxh64(b"abc", 0), 0x44bc_2cf5_ad77_0999);
+    assert_eq!(
+        xxh64(b"Nobody inspects the spammish repetition", 0),
+        0xfbce_a83c_8a37_8bf1
+    );
+}
+
+//------------------------------------------------------------------------------
+
+/// The cryptographic hash (`md5`, `sha1`, `sha256`, `sha512`) SQL functions.
+#[derive(Debug)]
+pub enum Digest {
+    /// MD5 (`md5`).
+    Md5,
+    /// SHA-1 (`sha1`).
+    Sha1,
+    /// SHA-256 (`sha256`).
+    Sha256,
+    /// SHA-512 (`sha512`).
+    Sha512,
+}
+
+impl Function for Digest {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = match self {
+            Self::Md5 => "md5",
+            Self::Sha1 => "sha1",
+            Self::Sha256 => "sha256",
+            Self::Sha512 => "sha512",
+        };
+        let input = args_1::<Value>(name, args, None)?;
+        let result = canonical_bytes(input)?.map(|input| match self {
+            Self::Md5 => Md5::digest(&input).to_vec(),
+            Self::Sha1 => Sha1::digest(&input).to_vec(),
+            Self::Sha256 => Sha256::digest(&input).to_vec(),
+            Self::Sha512 => Sha512::digest(&input).to_vec(),
+        });
+        Ok(Compiled(C::Constant(result.map_or(Value::Null, Value::new_binary))))
+    }
+}
+
+/// The non-cryptographic hash (`crc32`, `fnv1a32`, `fnv1a64`) SQL functions.
+#[derive(Debug)]
+pub enum Checksum {
+    /// CRC-32 as used by zlib (`crc32`).
+    Crc32,
+    /// 32-bit FNV-1a (`fnv1a32`).
+    Fnv1a32,
+    /// 64-bit FNV-1a (`fnv1a64`).
+    Fnv1a64,
+}
+
+impl Function for Checksum {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = match self {
+            Self::Crc32 => "crc32",
+            Self::Fnv1a32 => "fnv1a32",
+            Self::Fnv1a64 => "fnv1a64",
+        };
+        let input = args_1::<Value>(name, args, None)?;
+        let result = canonical_bytes(input)?.map(|input| match self {
+            Self::Crc32 => u64::from(crc32fast::hash(&input)),
+            Self::Fnv1a32 => u64::from(
+                input
+                    .iter()
+                    .fold(0x811c_9dc5_u32, |h, b| (h ^ u32::from(*b)).wrapping_mul(0x0100_0193)),
+            ),
+            Self::Fnv1a64 => input.iter().fold(0xcbf2_9ce4_8422_2325_u64, |h, b| {
+                (h ^ u64::from(*b)).wrapping_mul(0x0000_0100_0000_01b3)
+            }),
+        });
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+/// The `xxh64` SQL function.
+#[derive(Debug)]
+pub struct Xxh64;
+
+impl Function for Xxh64 {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (input, seed) = args_2::<Value, u64>("xxh64", args, None, Some(0))?;
+        let result = canonical_bytes(input)?.map(|input| xxh64(&input, seed));
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `to_hex` SQL function.
+#[derive(Debug)]
+pub struct ToHex;
+
+impl Function for ToHex {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let input = args_1::<Vec<u8>>("to_hex", args, None)?;
+        Ok(Compiled(C::Constant(HEXLOWER.encode(&input).into())))
+    }
+}
+
+/// The `from_hex` SQL function.
+#[derive(Debug)]
+pub struct FromHex;
+
+impl Function for FromHex {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "from_hex";
+        let input = args_1::<Vec<u8>>(name, args, None)?;
+        let result = HEXLOWER_PERMISSIVE
+            .decode(&input)
+            .map_err(|e| Error::InvalidArguments {
+                name,
+                cause: format!("invalid hex string: {e}"),
+            })?;
+        Ok(Compiled(C::Constant(Value::new_binary(result))))
+    }
+}
+
+/// The `to_base64` SQL function.
+#[derive(Debug)]
+pub struct ToBase64;
+
+impl Function for ToBase64 {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let input = args_1::<Vec<u8>>("to_base64", args, None)?;
+        Ok(Compiled(C::Constant(BASE64.encode(&input).into())))
+    }
+}
+
+/// The `from_base64` SQL function.
+#[derive(Debug)]
+pub struct FromBase64;
+
+impl Function for FromBase64 {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "from_base64";
+        let input = args_1::<Vec<u8>>(name, args, None)?;
+        let result = BASE64.decode(&input).map_err(|e| Error::InvalidArguments {
+            name,
+            cause: format!("invalid base64 string: {e}"),
+        })?;
+        Ok(Compiled(C::Constant(Value::new_binary(result))))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `url_encode` SQL function.
+#[derive(Debug)]
+pub struct UrlEncode;
+
+impl Function for UrlEncode {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let input = args_1::<Vec<u8>>("url_encode", args, None)?;
+        let mut result = String::with_capacity(input.len());
+        for b in input {
+            if b.is_ascii_alphanumeric() || b"-._~".contains(&b) {
+                result.push(char::from(b));
+            } else {
+                result.push('%');
+                result.push_str(&HEXUPPER.encode(&[b]));
+            }
+        }
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+/// The `url_decode` SQL function.
+#[derive(Debug)]
+pub struct UrlDecode;
+
+impl Function for UrlDecode {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "url_decode";
+        let input = args_1::<Vec<u8>>(name, args, None)?;
+        let mut result = Vec::with_capacity(input.len());
+        let mut it = input.iter();
+        while let Some(&b) = it.next() {
+            match b {
+                b'%' => {
+                    let hex = it.as_slice().get(..2).unwrap_or_default();
+                    let decoded = HEXLOWER_PERMISSIVE.decode(hex).ok().filter(|d| d.len() == 1);
+                    result.push(
+                        decoded.ok_or_else(|| Error::InvalidArguments {
+                            name,
+                            cause: "incomplete percent-encoded sequence".to_owned(),
+                        })?[0],
+                    );
+                    it.nth(1);
+                }
+                b'+' => result.push(b' '),
+                _ => result.push(b),
+            }
+        }
+        Ok(Compiled(C::Constant(Value::new_binary(result))))
+    }
+}
+
+/// The `convert_from` SQL function.
+#[derive(Debug)]
+pub struct ConvertFrom;
+
+impl Function for ConvertFrom {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "convert_from";
+        let input = args_1::<Vec<u8>>(name, args, None)?;
+        let result = String::from_utf8(input).map_err(|e| Error::InvalidArguments {
+            name,
+            cause: format!("invalid UTF-8 string: {e}"),
+        })?;
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `quote_ident` and `quote_literal` SQL functions.
+#[derive(Debug)]
+pub struct Quote {
+    /// The quotation mark, either `"` (`quote_ident`) or `'` (`quote_literal`).
+    pub quote: u8,
+}
+
+impl Function for Quote {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = if self.quote == b'"' {
+            "quote_ident"
+        } else {
+            "quote_literal"
+        };
+        let input = args_1::<Bytes>(name, args, None)?;
+        let mut result = Vec::with_capacity(input.as_bytes().len() + 2);
+        result.push(self.quote);
+        for &b in input.as_bytes() {
+            if b == self.quote {
+                result.push(b);
+            }
+            result.push(b);
+        }
+        result.push(self.quote);
+        // quoting keeps a text string valid UTF-8, so only binary strings need to be marked.
+        let result = if input.is_binary() {
+            Value::new_binary(result)
+        } else {
+            result.into()
+        };
+        Ok(Compiled(C::Constant(result)))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `inet_aton` SQL function.
+#[derive(Debug)]
+pub struct InetAton;
+
+impl Function for InetAton {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let input = args_1::<Value>("inet_aton", args, None)?;
+        let result = match input {
+            Value::Bytes(bytes) => from_utf8(bytes.as_bytes())
+                .ok()
+                .and_then(|s| s.parse::<Ipv4Addr>().ok())
+                .map_or(Value::Null, |addr| u32::from(addr).into()),
+            _ => Value::Null,
+        };
+        Ok(Compiled(C::Constant(result)))
+    }
+}
+
+/// The `inet_ntoa` SQL function.
+#[derive(Debug)]
+pub struct InetNtoa;
+
+impl Function for InetNtoa {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let input = args_1::<Value>("inet_ntoa", args, None)?;
+        let addr = match input {
+            Value::Number(n) => n.to::<u32>(),
+            Value::Bytes(bytes) => <[u8; 4]>::try_from(bytes.as_bytes()).ok().map(u32::from_be_bytes),
+            _ => None,
+        };
+        Ok(Compiled(C::Constant(
+            addr.map_or(Value::Null, |addr| Ipv4Addr::from(addr).to_string().into()),
+        )))
+    }
+}
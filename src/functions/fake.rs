@@ -0,0 +1,352 @@
+//! Fake data generator functions, backed by embedded word lists.
+
+use super::{args_1, args_2, require, Arguments, Function};
+use crate::{
+    error::Error,
+    eval::{CompileContext, Compiled, C},
+};
+use rand::{seq::SliceRandom, Rng};
+
+/// Word lists and format patterns of a locale.
+#[derive(Debug)]
+pub struct Locale {
+    /// The locale name, e.g. `en_US`.
+    name: &'static str,
+    first_names: &'static [&'static str],
+    last_names: &'static [&'static str],
+    streets: &'static [&'static str],
+    cities: &'static [&'static str],
+    company_suffixes: &'static [&'static str],
+    /// Patterns of `fake.street_address`.
+    street_address_patterns: &'static [&'static str],
+    /// Patterns of `fake.company`.
+    company_patterns: &'static [&'static str],
+    /// Patterns of `fake.phone`.
+    phone_patterns: &'static [&'static str],
+}
+
+#[rustfmt::skip]
+static EN_US: Locale = Locale {
+    name: "en_US",
+    first_names: &[
+        "James", "Mary", "John", "Patricia", "Robert", "Jennifer", "Michael", "Linda", "William", "Elizabeth",
+        "David", "Barbara", "Richard", "Susan", "Joseph", "Jessica", "Thomas", "Sarah", "Charles", "Karen",
+        "Christopher", "Nancy", "Daniel", "Lisa", "Matthew", "Betty", "Anthony", "Margaret", "Mark", "Sandra",
+        "Donald", "Ashley", "Steven", "Kimberly", "Paul", "Emily", "Andrew", "Donna", "Joshua", "Michelle",
+    ],
+    last_names: &[
+        "Smith", "Johnson", "Williams", "Brown", "Jones", "Garcia", "Miller", "Davis", "Rodriguez", "Martinez",
+        "Hernandez", "Lopez", "Gonzalez", "Wilson", "Anderson", "Thomas", "Taylor", "Moore", "Jackson", "Martin",
+        "Lee", "Perez", "Thompson", "White", "Harris", "Sanchez", "Clark", "Ramirez", "Lewis", "Robinson",
+        "Walker", "Young", "Allen", "King", "Wright", "Scott", "Torres", "Nguyen", "Hill", "Flores",
+    ],
+    streets: &[
+        "Main Street", "Oak Street", "Pine Street", "Maple Avenue", "Cedar Lane", "Elm Street", "Washington Avenue",
+        "Lake Drive", "Hill Road", "Park Avenue", "Sunset Boulevard", "Church Street", "Highland Avenue",
+        "River Road", "Spring Street", "Jefferson Street", "Forest Drive", "Meadow Lane", "Lincoln Avenue",
+        "Willow Way",
+    ],
+    cities: &[
+        "New York", "Los Angeles", "Chicago", "Houston", "Phoenix", "Philadelphia", "San Antonio", "San Diego",
+        "Dallas", "San Jose", "Austin", "Jacksonville", "Columbus", "Charlotte", "Indianapolis", "Seattle",
+        "Denver", "Boston", "Nashville", "Portland", "Springfield", "Madison", "Georgetown", "Franklin",
+    ],
+    company_suffixes: &["Inc.", "LLC", "Group", "Corp.", "and Sons", "Holdings"],
+    street_address_patterns: &["%# {street}", "%## {street}", "%### {street}", "%## {street} Apt. %#"],
+    company_patterns: &["{last_name} {company_suffix}", "{last_name}, {last_name} and {last_name}", "{last_name}-{last_name}"],
+    phone_patterns: &["(%##) %##-####", "%##-%##-####", "+1 %##-%##-####"],
+};
+
+#[rustfmt::skip]
+static DE_DE: Locale = Locale {
+    name: "de_DE",
+    first_names: &[
+        "Maximilian", "Sophie", "Alexander", "Marie", "Paul", "Sophia", "Elias", "Emma", "Ben", "Hannah",
+        "Noah", "Emilia", "Leon", "Anna", "Louis", "Mia", "Jonas", "Lena", "Felix", "Lea",
+        "Lukas", "Johanna", "Jürgen", "Jörg", "Uwe", "Ursula", "Günther", "Käthe", "Sören", "Björn",
+    ],
+    last_names: &[
+        "Müller", "Schmidt", "Schneider", "Fischer", "Weber", "Meyer", "Wagner", "Becker", "Schulz", "Hoffmann",
+        "Schäfer", "Koch", "Bauer", "Richter", "Klein", "Wolf", "Schröder", "Neumann", "Schwarz", "Zimmermann",
+        "Braun", "Krüger", "Hofmann", "Hartmann", "Lange", "Schmitt", "Werner", "Schmitz", "Krause", "Meier",
+    ],
+    streets: &[
+        "Hauptstraße", "Schulstraße", "Gartenstraße", "Bahnhofstraße", "Dorfstraße", "Bergstraße", "Birkenweg",
+        "Lindenstraße", "Kirchstraße", "Waldstraße", "Ringstraße", "Schillerstraße", "Goethestraße",
+        "Am Markt", "Mühlenweg", "Rosenweg", "Feldstraße", "Friedhofstraße", "Wiesenweg", "Jahnstraße",
+    ],
+    cities: &[
+        "Berlin", "Hamburg", "München", "Köln", "Frankfurt am Main", "Stuttgart", "Düsseldorf", "Leipzig",
+        "Dortmund", "Essen", "Bremen", "Dresden", "Hannover", "Nürnberg", "Duisburg", "Bochum",
+        "Wuppertal", "Bielefeld", "Bonn", "Münster", "Lübeck", "Göttingen", "Würzburg", "Saarbrücken",
+    ],
+    company_suffixes: &["GmbH", "AG", "KG", "GmbH & Co. KG", "e.K.", "OHG"],
+    street_address_patterns: &["{street} %", "{street} %#", "{street} %#a"],
+    company_patterns: &["{last_name} {company_suffix}", "{last_name} & {last_name} {company_suffix}"],
+    phone_patterns: &["+49 %## #######", "0%## #######", "0%### ######", "+49 1%# ########"],
+};
+
+#[rustfmt::skip]
+static FR_FR: Locale = Locale {
+    name: "fr_FR",
+    first_names: &[
+        "Gabriel", "Louise", "Raphaël", "Ambre", "Léo", "Jade", "Louis", "Emma", "Lucas", "Rose",
+        "Adam", "Alice", "Arthur", "Chloé", "Hugo", "Léna", "Jules", "Inès", "Maël", "Anna",
+        "Noé", "Zoé", "Théo", "Léonie", "Nathan", "Héloïse", "François", "Cécile", "Jérôme", "Hélène",
+    ],
+    last_names: &[
+        "Martin", "Bernard", "Thomas", "Petit", "Robert", "Richard", "Durand", "Dubois", "Moreau", "Laurent",
+        "Simon", "Michel", "Lefèvre", "Leroy", "Roux", "David", "Bertrand", "Morel", "Fournier", "Girard",
+        "Bonnet", "Dupont", "Lambert", "Fontaine", "Rousseau", "Vincent", "Muller", "Lefebvre", "Faure", "André",
+    ],
+    streets: &[
+        "rue de la Paix", "rue Victor Hugo", "avenue des Champs-Élysées", "boulevard Saint-Germain",
+        "rue de l'Église", "place de la République", "rue du Moulin", "chemin des Vignes", "rue Pasteur",
+        "avenue Jean Jaurès", "rue de la Gare", "impasse des Lilas", "rue Nationale", "quai de la Seine",
+        "allée des Châtaigniers", "rue du Château",
+    ],
+    cities: &[
+        "Paris", "Marseille", "Lyon", "Toulouse", "Nice", "Nantes", "Montpellier", "Strasbourg",
+        "Bordeaux", "Lille", "Rennes", "Reims", "Saint-Étienne", "Le Havre", "Toulon", "Grenoble",
+        "Dijon", "Angers", "Nîmes", "Aix-en-Provence", "Besançon", "Orléans",
+    ],
+    company_suffixes: &["SARL", "SA", "SAS", "EURL", "et Fils"],
+    street_address_patterns: &["% {street}", "%# {street}", "%## {street}", "%# bis {street}"],
+    company_patterns: &["{last_name} {company_suffix}", "{last_name} et {last_name}"],
+    phone_patterns: &["0% ## ## ## ##", "+33 % ## ## ## ##"],
+};
+
+#[rustfmt::skip]
+static JA_JP: Locale = Locale {
+    name: "ja_JP",
+    first_names: &[
+        "翔太", "陽菜", "蓮", "結衣", "大翔", "美咲", "悠真", "さくら", "湊", "葵",
+        "健太", "愛", "拓海", "優奈", "颯太", "凛", "大輝", "花子", "太郎", "明美",
+    ],
+    last_names: &[
+        "佐藤", "鈴木", "高橋", "田中", "伊藤", "渡辺", "山本", "中村", "小林", "加藤",
+        "吉田", "山田", "佐々木", "山口", "松本", "井上", "木村", "林", "斎藤", "清水",
+    ],
+    streets: &["中央", "本町", "栄町", "緑町", "旭町", "幸町", "東町", "西町", "南町", "北町", "新町", "元町"],
+    cities: &[
+        "東京都千代田区", "東京都新宿区", "横浜市", "大阪市", "名古屋市", "札幌市", "福岡市", "神戸市",
+        "京都市", "川崎市", "さいたま市", "広島市", "仙台市", "千葉市", "北九州市", "那覇市",
+    ],
+    company_suffixes: &["株式会社", "有限会社", "合同会社"],
+    street_address_patterns: &["{city}{street}%丁目%#-%", "{city}{street}%-%#-%#"],
+    company_patterns: &["{company_suffix}{last_name}", "{last_name}{company_suffix}"],
+    phone_patterns: &["0%-####-####", "090-####-####", "080-####-####", "0%#-###-####"],
+};
+
+/// All supported locales. The first one is the default.
+static LOCALES: [&Locale; 4] = [&EN_US, &DE_DE, &FR_FR, &JA_JP];
+
+/// A selection of ISO 3166-1 alpha-2 country codes.
+#[rustfmt::skip]
+static COUNTRY_CODES: &[&str] = &[
+    "AR", "AT", "AU", "BE", "BR", "CA", "CH", "CL", "CN", "CO", "CZ", "DE", "DK", "EG", "ES", "FI", "FR", "GB",
+    "GR", "HK", "HU", "ID", "IE", "IL", "IN", "IT", "JP", "KE", "KR", "MX", "MY", "NG", "NL", "NO", "NZ", "PE",
+    "PH", "PK", "PL", "PT", "RO", "RU", "SA", "SE", "SG", "TH", "TR", "TW", "UA", "US", "VN", "ZA",
+];
+
+/// Domains used by `fake.email`. These are reserved for documentation, so the generated addresses
+/// can never reach a real mailbox.
+static EMAIL_DOMAINS: &[&str] = &["example.com", "example.net", "example.org"];
+
+/// Finds a locale by its name.
+fn find_locale(name: &'static str, locale: &str) -> Result<&'static Locale, Error> {
+    LOCALES
+        .iter()
+        .copied()
+        .find(|l| l.name.eq_ignore_ascii_case(locale))
+        .ok_or_else(|| Error::InvalidArguments {
+            name,
+            cause: format!(
+                "unknown locale '{locale}', expecting one of: {}",
+                LOCALES.iter().map(|l| l.name).collect::<Vec<_>>().join(", ")
+            ),
+        })
+}
+
+/// Precompiled fake data generator.
+#[derive(Clone, Debug)]
+pub enum FakeData {
+    /// Expands one of the patterns using the word lists of the locale.
+    ///
+    /// In a pattern, `{…}` picks a random word from the named list, `#` produces a random digit and
+    /// `%` produces a random non-zero digit.
+    Pattern {
+        /// The locale providing the word lists.
+        locale: &'static Locale,
+        /// The patterns to choose from.
+        patterns: &'static [&'static str],
+    },
+    /// Appends a random domain to the local part of an email address.
+    Email {
+        /// The local part, including the `@`.
+        local_part: String,
+    },
+}
+
+impl FakeData {
+    /// Generates a random string.
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        match self {
+            Self::Pattern { locale, patterns } => {
+                let pattern = patterns.choose(rng).unwrap();
+                let mut result = String::with_capacity(pattern.len() * 2);
+                let mut rest = *pattern;
+                while let Some(c) = rest.chars().next() {
+                    rest = &rest[c.len_utf8()..];
+                    match c {
+                        '#' => result.push(char::from(rng.gen_range(b'0', b'9' + 1))),
+                        '%' => result.push(char::from(rng.gen_range(b'1', b'9' + 1))),
+                        '{' => {
+                            let end = rest.find('}').unwrap();
+                            let words = match &rest[..end] {
+                                "first_name" => locale.first_names,
+                                "last_name" => locale.last_names,
+                                "street" => locale.streets,
+                                "city" => locale.cities,
+                                "company_suffix" => locale.company_suffixes,
+                                "country_code" => COUNTRY_CODES,
+                                token => unreachable!("unknown token {{{}}}", token),
+                            };
+                            result.push_str(words.choose(rng).unwrap());
+                            rest = &rest[end + 1..];
+                        }
+                        _ => result.push(c),
+                    }
+                }
+                result
+            }
+            Self::Email { local_part } => {
+                let mut result = local_part.clone();
+                result.push_str(EMAIL_DOMAINS.choose(rng).unwrap());
+                result
+            }
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The kind of data generated by the [`Fake`] function.
+#[derive(Copy, Clone, Debug)]
+pub enum Field {
+    /// `fake.first_name`
+    FirstName,
+    /// `fake.last_name`
+    LastName,
+    /// `fake.street_address`
+    StreetAddress,
+    /// `fake.city`
+    City,
+    /// `fake.company`
+    Company,
+    /// `fake.phone`
+    Phone,
+}
+
+/// The `fake.first_name`, `fake.last_name`, `fake.street_address`, `fake.city`, `fake.company` and
+/// `fake.phone` SQL functions.
+#[derive(Debug)]
+pub struct Fake {
+    /// The function name.
+    pub name: &'static str,
+    /// The kind of data to generate.
+    pub field: Field,
+}
+
+impl Function for Fake {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let locale = args_1::<String>(self.name, args, Some(EN_US.name.to_owned()))?;
+        let locale = find_locale(self.name, &locale)?;
+        let patterns = match self.field {
+            Field::FirstName => &["{first_name}"],
+            Field::LastName => &["{last_name}"],
+            Field::StreetAddress => locale.street_address_patterns,
+            Field::City => &["{city}"],
+            Field::Company => locale.company_patterns,
+            Field::Phone => locale.phone_patterns,
+        };
+        Ok(Compiled(C::RandFake(FakeData::Pattern { locale, patterns })))
+    }
+}
+
+/// The `fake.country_code` SQL function.
+#[derive(Debug)]
+pub struct CountryCode;
+
+impl Function for CountryCode {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        require("fake.country_code", args.is_empty(), || {
+            "no arguments expected".to_owned()
+        })?;
+        Ok(Compiled(C::RandFake(FakeData::Pattern {
+            locale: &EN_US,
+            patterns: &["{country_code}"],
+        })))
+    }
+}
+
+/// Appends the ASCII transliteration of a name to the local part of an email address. Letters
+/// with diacritics are folded to their base letters, and other unsupported characters are dropped.
+fn push_ascii_name(local_part: &mut String, name: &str) {
+    for c in name.chars().flat_map(char::to_lowercase) {
+        let folded = match c {
+            'a'..='z' | '0'..='9' => {
+                local_part.push(c);
+                continue;
+            }
+            'à' | 'á' | 'â' | 'ã' | 'å' => "a",
+            'ä' | 'æ' => "ae",
+            'ç' => "c",
+            'è' | 'é' | 'ê' | 'ë' => "e",
+            'ì' | 'í' | 'î' | 'ï' => "i",
+            'ñ' => "n",
+            'ò' | 'ó' | 'ô' | 'õ' | 'ø' => "o",
+            'ö' | 'œ' => "oe",
+            'ß' => "ss",
+            'ù' | 'ú' | 'û' => "u",
+            'ü' => "ue",
+            'ý' | 'ÿ' => "y",
+            _ => "",
+        };
+        local_part.push_str(folded);
+    }
+}
+
+/// The `fake.email` SQL function.
+#[derive(Debug)]
+pub struct Email;
+
+impl Function for Email {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (first, last) = args_2::<String, String>("fake.email", args, None, None)?;
+        let mut local_part = String::with_capacity(first.len() + last.len() + 2);
+        push_ascii_name(&mut local_part, &first);
+        let first_len = local_part.len();
+        push_ascii_name(&mut local_part, &last);
+        if local_part.is_empty() {
+            local_part.push_str("user");
+        } else if first_len != 0 && first_len != local_part.len() {
+            local_part.insert(first_len, '.');
+        }
+        local_part.push('@');
+        Ok(Compiled(C::RandFake(FakeData::Email { local_part })))
+    }
+}
+
+#[test]
+fn test_push_ascii_name() {
+    let mut s = String::new();
+    push_ascii_name(&mut s, "Jürgen");
+    assert_eq!(s, "juergen");
+    s.clear();
+    push_ascii_name(&mut s, "Lefèvre-Dupont");
+    assert_eq!(s, "lefevredupont");
+    s.clear();
+    push_ascii_name(&mut s, "佐藤");
+    assert_eq!(s, "");
+}
@@ -0,0 +1,366 @@
+//! Geometry functions.
+
+use super::{args_1, args_2, args_4, require, Arguments, Function};
+use crate::{
+    error::Error,
+    eval::{CompileContext, Compiled, C},
+    geometry::{Coord, Geometry, Shape},
+    value::Value,
+};
+use rand::{distributions::Uniform, Rng};
+use std::{convert::TryFrom, f64::consts::PI, sync::Arc};
+
+/// Checks that the numbers are finite.
+fn require_finite(name: &'static str, numbers: &[f64]) -> Result<(), Error> {
+    require(name, numbers.iter().all(|n| n.is_finite()), || {
+        "coordinates must be finite numbers".to_owned()
+    })
+}
+
+/// Converts an array of `[x, y]` arrays into a closed ring.
+fn ring_from_array(name: &'static str, vertices: &[Value]) -> Result<Box<[Coord]>, Error> {
+    let mut ring = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, vertex)| {
+            let cause = || format!("vertex #{} is not an array of 2 numbers", i + 1);
+            let vertex = Arc::<[Value]>::try_from(vertex.clone())
+                .map_err(|_| Error::InvalidArguments { name, cause: cause() })?;
+            match &*vertex {
+                [x, y] => match (f64::try_from(x.clone()), f64::try_from(y.clone())) {
+                    (Ok(x), Ok(y)) if x.is_finite() && y.is_finite() => Ok((x, y)),
+                    _ => Err(Error::InvalidArguments { name, cause: cause() }),
+                },
+                _ => Err(Error::InvalidArguments { name, cause: cause() }),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    require(name, ring.len() >= 3, || {
+        "a polygon must have at least 3 vertices".to_owned()
+    })?;
+    ring.push(ring[0]);
+    Ok(ring.into())
+}
+
+/// Computes the signed area of a closed ring using the shoelace formula.
+fn ring_area(ring: &[Coord]) -> f64 {
+    ring.windows(2).map(|w| w[0].0 * w[1].1 - w[1].0 * w[0].1).sum::<f64>() / 2.0
+}
+
+/// Checks whether the point is inside the closed ring, using the even-odd rule.
+fn ring_contains(ring: &[Coord], (x, y): Coord) -> bool {
+    let mut inside = false;
+    for w in ring.windows(2) {
+        let ((x1, y1), (x2, y2)) = (w[0], w[1]);
+        if (y1 > y) != (y2 > y) && x < (x2 - x1) * (y - y1) / (y2 - y1) + x1 {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Generator of random geometries.
+#[derive(Clone, Debug)]
+pub enum GeometryGenerator {
+    /// A point uniformly distributed in a rectangle.
+    Point {
+        /// Distribution of the x coordinate.
+        x: Uniform<f64>,
+        /// Distribution of the y coordinate.
+        y: Uniform<f64>,
+    },
+    /// A point uniformly distributed in a polygon.
+    PointIn {
+        /// The closed exterior ring of the polygon.
+        ring: Arc<[Coord]>,
+        /// Distribution of the x coordinate of the bounding box.
+        x: Uniform<f64>,
+        /// Distribution of the y coordinate of the bounding box.
+        y: Uniform<f64>,
+    },
+    /// A random walk.
+    LineString {
+        /// The starting point.
+        start: Coord,
+        /// Maximum distance between two consecutive points.
+        step: f64,
+        /// Number of points.
+        points: usize,
+    },
+    /// A star-shaped polygon around a center.
+    Polygon {
+        /// The center.
+        center: Coord,
+        /// Maximum distance between the center and the vertices.
+        radius: f64,
+        /// Number of vertices.
+        vertices: usize,
+    },
+}
+
+impl GeometryGenerator {
+    /// Generates a random geometry.
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Geometry {
+        let shape = match self {
+            Self::Point { x, y } => Shape::Point((rng.sample(x), rng.sample(y))),
+            Self::PointIn { ring, x, y } => loop {
+                let point = (rng.sample(x), rng.sample(y));
+                if ring_contains(ring, point) {
+                    break Shape::Point(point);
+                }
+            },
+            Self::LineString { start, step, points } => {
+                let mut current = *start;
+                let mut coords = Vec::with_capacity(*points);
+                coords.push(current);
+                for _ in 1..*points {
+                    let angle = rng.gen_range(0.0, 2.0 * PI);
+                    let distance = rng.gen_range(0.0, *step);
+                    current.0 += distance * angle.cos();
+                    current.1 += distance * angle.sin();
+                    coords.push(current);
+                }
+                Shape::LineString(coords.into())
+            }
+            Self::Polygon {
+                center,
+                radius,
+                vertices,
+            } => {
+                // each vertex is placed in the first half of its own sector, so consecutive
+                // vertices are less than 3π/n ≤ π apart. the center is then strictly inside, and
+                // the polygon is star-shaped around it, hence simple and counter-clockwise.
+                #[allow(clippy::cast_precision_loss)]
+                let sector = 2.0 * PI / *vertices as f64;
+                let mut exterior = (0..*vertices)
+                    .map(|i| {
+                        #[allow(clippy::cast_precision_loss)]
+                        let angle = (i as f64 + rng.gen_range(0.0, 0.5)) * sector;
+                        let distance = rng.gen_range(radius / 2.0, *radius);
+                        (center.0 + distance * angle.cos(), center.1 + distance * angle.sin())
+                    })
+                    .collect::<Vec<_>>();
+                exterior.push(exterior[0]);
+                Shape::Polygon(vec![exterior.into_boxed_slice()].into())
+            }
+        };
+        shape.into()
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `rand.point` SQL function.
+#[derive(Debug)]
+pub struct RandPoint;
+
+impl Function for RandPoint {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.point";
+        let (min_x, min_y, max_x, max_y) = args_4::<f64, f64, f64, f64>(name, args, None, None, None, None)?;
+        require_finite(name, &[min_x, min_y, max_x, max_y])?;
+        require(name, min_x <= max_x && min_y <= max_y, || {
+            format!("the bounding box ({min_x}, {min_y}) - ({max_x}, {max_y}) is empty")
+        })?;
+        Ok(Compiled(C::RandGeometry(GeometryGenerator::Point {
+            x: Uniform::new_inclusive(min_x, max_x),
+            y: Uniform::new_inclusive(min_y, max_y),
+        })))
+    }
+}
+
+/// The `rand.point_in` SQL function.
+#[derive(Debug)]
+pub struct RandPointIn;
+
+impl Function for RandPointIn {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.point_in";
+        let vertices = args_1::<Arc<[Value]>>(name, args, None)?;
+        let ring = ring_from_array(name, &vertices)?;
+        require(name, ring_area(&ring) != 0.0, || {
+            "the polygon must have a non-zero area".to_owned()
+        })?;
+        let (mut min, mut max) = (ring[0], ring[0]);
+        for &(x, y) in &*ring {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+        Ok(Compiled(C::RandGeometry(GeometryGenerator::PointIn {
+            ring: ring.into(),
+            x: Uniform::new_inclusive(min.0, max.0),
+            y: Uniform::new_inclusive(min.1, max.1),
+        })))
+    }
+}
+
+/// The `rand.linestring` SQL function.
+#[derive(Debug)]
+pub struct RandLineString;
+
+impl Function for RandLineString {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.linestring";
+        let (x, y, step, points) = args_4::<f64, f64, f64, usize>(name, args, None, None, None, None)?;
+        require_finite(name, &[x, y])?;
+        require(name, step.is_finite() && step > 0.0, || {
+            format!("step ({step}) must be a positive number")
+        })?;
+        require(name, points >= 2, || {
+            format!("a linestring must have at least 2 points, not {points}")
+        })?;
+        Ok(Compiled(C::RandGeometry(GeometryGenerator::LineString {
+            start: (x, y),
+            step,
+            points,
+        })))
+    }
+}
+
+/// The `rand.polygon` SQL function.
+#[derive(Debug)]
+pub struct RandPolygon;
+
+impl Function for RandPolygon {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.polygon";
+        let (x, y, radius, vertices) = args_4::<f64, f64, f64, usize>(name, args, None, None, None, None)?;
+        require_finite(name, &[x, y])?;
+        require(name, radius.is_finite() && radius > 0.0, || {
+            format!("radius ({radius}) must be a positive number")
+        })?;
+        require(name, vertices >= 3, || {
+            format!("a polygon must have at least 3 vertices, not {vertices}")
+        })?;
+        Ok(Compiled(C::RandGeometry(GeometryGenerator::Polygon {
+            center: (x, y),
+            radius,
+            vertices,
+        })))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `st_point` SQL function.
+#[derive(Debug)]
+pub struct Point;
+
+impl Function for Point {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "st_point";
+        let (x, y) = args_2::<f64, f64>(name, args, None, None)?;
+        require_finite(name, &[x, y])?;
+        Ok(Compiled(C::Constant(Geometry::from(Shape::Point((x, y))).into())))
+    }
+}
+
+/// The `geography` SQL function.
+#[derive(Debug)]
+pub struct Geography;
+
+impl Function for Geography {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "geography";
+        let geometry = args_1::<Arc<Geometry>>(name, args, None)?;
+        let in_range = |&(lon, lat): &Coord| (-180.0..=180.0).contains(&lon) && (-90.0..=90.0).contains(&lat);
+        let is_valid = match &geometry.shape {
+            Shape::Point(coord) => in_range(coord),
+            Shape::LineString(coords) => coords.iter().all(in_range),
+            Shape::Polygon(rings) => rings.iter().flat_map(|r| r.iter()).all(in_range),
+        };
+        require(name, is_valid, || {
+            format!("coordinates of {geometry} are not valid longitudes and latitudes")
+        })?;
+        let mut geometry = Geometry::clone(&geometry);
+        geometry.is_geography = true;
+        Ok(Compiled(C::Constant(geometry.into())))
+    }
+}
+
+/// The `st_astext` SQL function.
+#[derive(Debug)]
+pub struct AsText;
+
+impl Function for AsText {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let geometry = args_1::<Arc<Geometry>>("st_astext", args, None)?;
+        Ok(Compiled(C::Constant(geometry.to_string().into())))
+    }
+}
+
+/// The `st_asbinary` SQL function.
+#[derive(Debug)]
+pub struct AsBinary;
+
+impl Function for AsBinary {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let geometry = args_1::<Arc<Geometry>>("st_asbinary", args, None)?;
+        Ok(Compiled(C::Constant(Value::new_binary(geometry.to_wkb()))))
+    }
+}
+
+#[test]
+fn test_ring_contains() {
+    let ring = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 1.0), (0.0, 4.0), (0.0, 0.0)];
+    assert!(ring_contains(&ring, (1.0, 0.5)));
+    assert!(ring_contains(&ring, (3.5, 3.0)));
+    assert!(!ring_contains(&ring, (2.0, 3.0)));
+    assert!(!ring_contains(&ring, (5.0, 1.0)));
+    assert!((ring_area(&ring) - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_rand_polygon_is_simple() {
+    use rand::SeedableRng;
+
+    // checks whether the segments p1-p2 and q1-q2 intersect, including touching.
+    fn segments_intersect((p1, p2): (Coord, Coord), (q1, q2): (Coord, Coord)) -> bool {
+        let cross = |o: Coord, a: Coord, b: Coord| (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0);
+        let (d1, d2) = (cross(q1, q2, p1), cross(q1, q2, p2));
+        let (d3, d4) = (cross(p1, p2, q1), cross(p1, p2, q2));
+        d1 * d2 <= 0.0 && d3 * d4 <= 0.0
+    }
+
+    let mut rng = rand_hc::Hc128Rng::seed_from_u64(1);
+    for vertices in 3..=12 {
+        let generator = GeometryGenerator::Polygon {
+            center: (1.0, -2.0),
+            radius: 5.0,
+            vertices,
+        };
+        for _ in 0..1000 {
+            let rings = match generator.sample(&mut rng).shape {
+                Shape::Polygon(rings) => rings,
+                shape => panic!("expected a polygon, got {:?}", shape),
+            };
+            let ring = &rings[0];
+            assert_eq!(ring.len(), vertices + 1);
+            assert!(ring_area(ring) > 0.0, "{:?} is not counter-clockwise", ring);
+            assert!(
+                ring_contains(ring, (1.0, -2.0)),
+                "{:?} does not contain the center",
+                ring
+            );
+            let edges = ring.windows(2).map(|w| (w[0], w[1])).collect::<Vec<_>>();
+            for i in 0..vertices {
+                // skip the neighboring edges which share a vertex with edge i.
+                for j in i + 2..vertices {
+                    if i == 0 && j == vertices - 1 {
+                        continue;
+                    }
+                    assert!(
+                        !segments_intersect(edges[i], edges[j]),
+                        "edges {} and {} of {:?} intersect",
+                        i,
+                        j,
+                        ring
+                    );
+                }
+            }
+        }
+    }
+}
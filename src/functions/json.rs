@@ -0,0 +1,247 @@
+//! JSON functions.
+
+use super::{args_1, args_2, Arguments, Function};
+use crate::{
+    error::Error,
+    eval::{CompileContext, Compiled, C},
+    value::Value,
+};
+use serde_json::{Map, Value as Json};
+use std::{convert::TryFrom, iter::once};
+
+/// Converts an SQL value into JSON.
+///
+/// Strings become JSON strings and arrays become JSON arrays. Timestamps, intervals and geometries
+/// are converted to strings in the same way as the `||` operator. Binary strings are rejected since
+/// JSON cannot represent them.
+fn to_json(name: &'static str, value: Value) -> Result<Json, Error> {
+    Ok(match value {
+        Value::Null => Json::Null,
+        Value::Number(n) => n.to_json(),
+        Value::Json(j) => Json::clone(&j),
+        Value::Array(array) => Json::Array(
+            array
+                .iter()
+                .map(|v| to_json(name, v.clone()))
+                .collect::<Result<_, _>>()?,
+        ),
+        value => match String::try_from(Value::sql_concat(once(value))?) {
+            Ok(s) => Json::String(s),
+            Err(_) => {
+                return Err(Error::InvalidArguments {
+                    name,
+                    cause: "cannot convert a binary string to JSON".to_owned(),
+                })
+            }
+        },
+    })
+}
+
+/// Converts an SQL value into a JSON document. Strings are parsed as JSON text instead of being
+/// treated as a JSON string.
+fn to_json_document(name: &'static str, value: Value) -> Result<Json, Error> {
+    match value {
+        Value::Bytes(_) => {
+            let text = String::try_from(value).map_err(|_| Error::InvalidArguments {
+                name,
+                cause: "cannot parse a binary string as JSON".to_owned(),
+            })?;
+            serde_json::from_str(&text).map_err(|e| Error::InvalidArguments {
+                name,
+                cause: format!("invalid JSON: {e}"),
+            })
+        }
+        value => to_json(name, value),
+    }
+}
+
+/// Converts a JSON value back into an SQL value. Objects and arrays are kept as JSON.
+fn from_json(json: &Json) -> Value {
+    match json {
+        Json::Null => Value::Null,
+        Json::Bool(b) => (*b).into(),
+        Json::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                i.into()
+            } else if let Some(u) = n.as_u64() {
+                u.into()
+            } else {
+                n.as_f64().map_or(Value::Null, Value::from)
+            }
+        }
+        Json::String(s) => s.clone().into(),
+        Json::Array(_) | Json::Object(_) => json.clone().into(),
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `json_object` SQL function.
+#[derive(Debug)]
+pub struct Object;
+
+impl Function for Object {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "json_object";
+        if !args.len().is_multiple_of(2) {
+            return Err(Error::InvalidArguments {
+                name,
+                cause: "expecting an even number of arguments".to_owned(),
+            });
+        }
+        let mut map = Map::new();
+        let mut args = args.into_iter().enumerate();
+        while let (Some((index, key)), Some((_, value))) = (args.next(), args.next()) {
+            let key = String::try_from(key).map_err(|_| Error::InvalidArgumentType {
+                name,
+                index,
+                expected: "string".to_owned(),
+            })?;
+            map.insert(key, to_json(name, value)?);
+        }
+        Ok(Compiled(C::Constant(Json::Object(map).into())))
+    }
+}
+
+/// The `json_array` SQL function.
+#[derive(Debug)]
+pub struct Array;
+
+impl Function for Array {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let array = args
+            .into_iter()
+            .map(|v| to_json("json_array", v))
+            .collect::<Result<_, _>>()?;
+        Ok(Compiled(C::Constant(Json::Array(array).into())))
+    }
+}
+
+/// The `json_bool` SQL function.
+#[derive(Debug)]
+pub struct Bool;
+
+impl Function for Bool {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let value = args_1::<Option<bool>>("json_bool", args, None)?;
+        Ok(Compiled(C::Constant(
+            value.map_or(Value::Null, |b| Json::Bool(b).into()),
+        )))
+    }
+}
+
+/// Applies a JSON merge patch (RFC 7396) onto the target.
+fn merge_patch(target: &mut Json, patch: Json) {
+    if let Json::Object(patch) = patch {
+        if !target.is_object() {
+            *target = Json::Object(Map::new());
+        }
+        let target = target.as_object_mut().unwrap();
+        for (key, value) in patch {
+            if value.is_null() {
+                target.remove(&key);
+            } else {
+                merge_patch(target.entry(key).or_insert(Json::Null), value);
+            }
+        }
+    } else {
+        *target = patch;
+    }
+}
+
+/// The `json_merge` SQL function.
+#[derive(Debug)]
+pub struct Merge;
+
+impl Function for Merge {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "json_merge";
+        let mut args = args.into_iter();
+        let mut result = to_json_document(name, args.next().ok_or(Error::NotEnoughArguments(name))?)?;
+        for patch in args {
+            merge_patch(&mut result, to_json_document(name, patch)?);
+        }
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+/// A step in a JSON path.
+#[derive(Debug, PartialEq)]
+enum PathStep {
+    /// Member of an object.
+    Key(String),
+    /// Element of an array.
+    Index(usize),
+}
+
+/// Parses a JSON path like `$.a."b c"[0]`.
+fn parse_path(path: &str) -> Option<Vec<PathStep>> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut steps = Vec::new();
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('[') {
+            let end = r.find(']')?;
+            steps.push(PathStep::Index(r[..end].trim().parse().ok()?));
+            rest = &r[end + 1..];
+        } else if let Some(r) = rest.strip_prefix(".\"") {
+            let end = r.find('"')?;
+            steps.push(PathStep::Key(r[..end].to_owned()));
+            rest = &r[end + 1..];
+        } else if let Some(r) = rest.strip_prefix('.') {
+            let end = r.find(['.', '[']).unwrap_or(r.len());
+            if end == 0 {
+                return None;
+            }
+            steps.push(PathStep::Key(r[..end].to_owned()));
+            rest = &r[end..];
+        } else {
+            return None;
+        }
+    }
+    Some(steps)
+}
+
+/// The `json_extract` SQL function.
+#[derive(Debug)]
+pub struct Extract;
+
+impl Function for Extract {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "json_extract";
+        let (doc, path) = args_2::<Value, String>(name, args, None, None)?;
+        let steps = parse_path(&path).ok_or_else(|| Error::InvalidArguments {
+            name,
+            cause: format!("invalid JSON path '{path}'"),
+        })?;
+        let doc = to_json_document(name, doc)?;
+        let result = steps.iter().try_fold(&doc, |json, step| match step {
+            PathStep::Key(key) => json.get(key),
+            PathStep::Index(index) => json.get(index),
+        });
+        Ok(Compiled(C::Constant(result.map_or(Value::Null, from_json))))
+    }
+}
+
+#[test]
+fn test_parse_path() {
+    assert_eq!(parse_path("$"), Some(vec![]));
+    assert_eq!(
+        parse_path("$.a.\"b c\"[2].d"),
+        Some(vec![
+            PathStep::Key("a".to_owned()),
+            PathStep::Key("b c".to_owned()),
+            PathStep::Index(2),
+            PathStep::Key("d".to_owned()),
+        ])
+    );
+    assert_eq!(parse_path("a.b"), None);
+    assert_eq!(parse_path("$..a"), None);
+    assert_eq!(parse_path("$[x]"), None);
+}
+
+#[test]
+fn test_merge_patch() {
+    let mut target = serde_json::json!({"a": 1, "b": {"c": 2, "d": 3}});
+    merge_patch(&mut target, serde_json::json!({"a": null, "b": {"c": [4]}, "e": 5}));
+    assert_eq!(target, serde_json::json!({"b": {"c": [4], "d": 3}, "e": 5}));
+}
@@ -0,0 +1,147 @@
+//! Functions loading values from external files.
+
+use super::{args_1, iter_args, require, Arguments, Function};
+use crate::{
+    error::Error,
+    eval::{CompileContext, Compiled, C},
+    value::{Number, Value},
+};
+use std::fs::read_to_string;
+
+/// Reads a file relative to the template directory into a string.
+pub(super) fn read_file(name: &'static str, ctx: &CompileContext, path: &str) -> Result<String, Error> {
+    let full_path = ctx.template_dir.join(path);
+    read_to_string(&full_path).map_err(|e| Error::InvalidArguments {
+        name,
+        cause: format!("cannot read '{}': {}", full_path.display(), e),
+    })
+}
+
+/// The `load_lines` SQL function.
+#[derive(Debug)]
+pub struct LoadLines;
+
+impl Function for LoadLines {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "load_lines";
+        let path = args_1::<String>(name, args, None)?;
+        let content = read_file(name, ctx, &path)?;
+        let lines = content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| Value::from(line.to_owned()))
+            .collect();
+        Ok(Compiled(C::Constant(Value::Array(lines))))
+    }
+}
+
+/// Converts a CSV cell written like a decimal number into a number. Returns `None` for everything
+/// else, including numbers with leading zeros such as `007`, since they are usually codes rather
+/// than quantities.
+fn csv_cell_to_number(cell: &str) -> Option<Value> {
+    let digits = cell.trim_start_matches(['-', '+']);
+    let has_leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits[1..].starts_with('.');
+    if !has_leading_zero && digits.starts_with(|c: char| c.is_ascii_digit()) {
+        if let Ok(n) = cell.parse::<i128>() {
+            return Some(Number::from(n).into());
+        }
+        if let Ok(f) = cell.parse::<f64>() {
+            if f.is_finite() {
+                return Some(f.into());
+            }
+        }
+    }
+    None
+}
+
+/// Converts the cells of a CSV column into values. The column becomes numbers only if every cell
+/// is a number, otherwise every cell stays as a string, so a column never mixes types. Missing
+/// cells become NULL.
+fn csv_column_to_values(cells: Vec<Option<String>>) -> Vec<Value> {
+    let numbers = cells
+        .iter()
+        .map(|cell| cell.as_deref().map_or(Some(Value::Null), csv_cell_to_number))
+        .collect::<Option<Vec<_>>>();
+    numbers.unwrap_or_else(|| {
+        cells
+            .into_iter()
+            .map(|cell| cell.map_or(Value::Null, Value::from))
+            .collect()
+    })
+}
+
+/// The `load_csv` SQL function.
+#[derive(Debug)]
+pub struct LoadCsv;
+
+impl Function for LoadCsv {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "load_csv";
+        let mut args = iter_args::<String>(name, args);
+        let path = args.next().ok_or(Error::NotEnoughArguments(name))??;
+        let column_names = args.collect::<Result<Vec<_>, _>>()?;
+        require(name, !column_names.is_empty(), || {
+            "expecting at least one column name".to_owned()
+        })?;
+
+        let content = read_file(name, ctx, &path)?;
+        let csv_error = |e: csv::Error| Error::InvalidArguments {
+            name,
+            cause: format!("cannot parse '{path}': {e}"),
+        };
+        let mut reader = csv::Reader::from_reader(content.as_bytes());
+        let headers = reader.headers().map_err(csv_error)?;
+        let column_indices = column_names
+            .iter()
+            .map(|column_name| {
+                headers
+                    .iter()
+                    .position(|h| h == column_name)
+                    .ok_or_else(|| Error::InvalidArguments {
+                        name,
+                        cause: format!("column '{column_name}' not found in '{path}'"),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut columns = vec![Vec::new(); column_indices.len()];
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            for (column, index) in columns.iter_mut().zip(&column_indices) {
+                column.push(record.get(*index).map(str::to_owned));
+            }
+        }
+
+        let mut columns = columns
+            .into_iter()
+            .map(|c| Value::Array(csv_column_to_values(c).into()))
+            .collect::<Vec<_>>();
+        let result = if columns.len() == 1 {
+            columns.pop().unwrap()
+        } else {
+            Value::Array(columns.into())
+        };
+        Ok(Compiled(C::Constant(result)))
+    }
+}
+
+#[test]
+fn test_csv_column_to_values() {
+    let column = |cells: &[Option<&str>]| csv_column_to_values(cells.iter().map(|c| c.map(str::to_owned)).collect());
+    assert_eq!(
+        column(&[Some("12"), Some("-1.5"), Some("0.25"), None]),
+        vec![Value::from(12), Value::from(-1.5), Value::from(0.25), Value::Null]
+    );
+    assert_eq!(
+        column(&[Some("007"), Some("100")]),
+        vec![Value::from("007".to_owned()), Value::from("100".to_owned())]
+    );
+    assert_eq!(
+        column(&[Some("1"), Some("inf")]),
+        vec![Value::from("1".to_owned()), Value::from("inf".to_owned())]
+    );
+    assert_eq!(
+        column(&[Some("12 apples"), Some("")]),
+        vec![Value::from("12 apples".to_owned()), Value::from(String::new())]
+    );
+}
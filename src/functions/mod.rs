@@ -7,9 +7,15 @@ use crate::value::Value;
 use std::{convert::TryFrom, fmt::Debug};
 
 pub mod array;
+pub mod codec;
+pub mod fake;
+pub mod geo;
+pub mod json;
+pub mod load;
 pub mod ops;
 pub mod rand;
 pub mod string;
+pub mod text;
 pub mod time;
 
 /// Container of the arguments passed to functions.
@@ -1,12 +1,13 @@
 //! Numerical and logical functions.
 
-use super::{args_1, args_2, iter_args, Arguments, Function};
+use super::{args_1, args_2, iter_args, require, Arguments, Function};
 use crate::{
+    decimal::{Decimal, MAX_SCALE},
     error::Error,
     eval::{CompileContext, Compiled, C},
     value::{Number, Value},
 };
-use std::cmp::Ordering;
+use std::{cmp::Ordering, convert::TryFrom};
 
 //------------------------------------------------------------------------------
 
@@ -165,12 +166,12 @@ pub enum Arith {
 }
 
 impl Function for Arith {
-    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
-        let func = match self {
-            Self::Add => Value::sql_add,
-            Self::Sub => Value::sql_sub,
-            Self::Mul => Value::sql_mul,
-            Self::FloatDiv => Value::sql_float_div,
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let func = |prev: &Value, cur: &Value| match self {
+            Self::Add => prev.sql_add(cur, ctx.time_zone),
+            Self::Sub => prev.sql_sub(cur, ctx.time_zone),
+            Self::Mul => prev.sql_mul(cur),
+            Self::FloatDiv => prev.sql_float_div(cur),
         };
 
         let result = args
@@ -216,6 +217,42 @@ impl Function for Bitwise {
 
 //------------------------------------------------------------------------------
 
+/// The bit shift (`<<`, `>>`) SQL functions.
+#[derive(Debug)]
+pub enum Shift {
+    /// Shift left (`<<`)
+    Left,
+    /// Arithmetic shift right (`>>`)
+    Right,
+}
+
+impl Function for Shift {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = match self {
+            Self::Left => "<<",
+            Self::Right => ">>",
+        };
+        let mut args = iter_args::<i128>(name, args);
+        let mut result = args.next().expect("at least 1 argument")?;
+        for amount in args {
+            let amount = amount?;
+            require(name, amount >= 0, || {
+                format!("cannot shift by negative amount {amount}")
+            })?;
+            result = match self {
+                Self::Left => u32::try_from(amount)
+                    .ok()
+                    .and_then(|a| result.checked_shl(a).filter(|r| r >> a == result))
+                    .ok_or_else(|| Error::IntegerOverflow(format!("{result} << {amount}")))?,
+                Self::Right => result >> amount.min(127),
+            };
+        }
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
 /// The extremum (`least`, `greatest`) SQL functions.
 #[derive(Debug)]
 pub struct Extremum {
@@ -259,13 +296,207 @@ pub struct Round;
 
 impl Function for Round {
     fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
-        let (value, digits) = args_2::<f64, i32>("round", args, None, Some(0))?;
-        let scale = 10.0_f64.powi(digits);
-        let result = (value * scale).round() / scale;
+        let (value, digits) = args_2::<Number, i32>("round", args, None, Some(0))?;
+        Ok(Compiled(C::Constant(value.round_to_digits(digits).into())))
+    }
+}
+
+/// The `decimal` SQL function.
+#[derive(Debug)]
+pub struct ToDecimal;
+
+impl Function for ToDecimal {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "decimal";
+        let (value, scale) = args_2::<Value, Value>(name, args, None, Some(Value::Null))?;
+        let decimal = match value {
+            Value::Null => return Ok(Compiled(C::Constant(Value::Null))),
+            Value::Number(n) => n.to_decimal(),
+            value => String::try_from(value)
+                .ok()
+                .and_then(|s| s.trim().parse::<Decimal>().ok()),
+        }
+        .ok_or_else(|| Error::InvalidArguments {
+            name,
+            cause: "cannot convert the value into a decimal".to_owned(),
+        })?;
+        let decimal =
+            match scale {
+                Value::Null => Some(decimal),
+                scale => {
+                    let scale = u8::try_from(scale).ok().filter(|s| *s <= MAX_SCALE).ok_or_else(|| {
+                        Error::InvalidArguments {
+                            name,
+                            cause: format!("scale must be an integer between 0 and {MAX_SCALE}"),
+                        }
+                    })?;
+                    decimal.rescale(scale, f64::round)
+                }
+            }
+            .ok_or(Error::IntegerOverflow(format!("decimal({decimal})")))?;
+        Ok(Compiled(C::Constant(Number::from(decimal).into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `abs` SQL function.
+#[derive(Debug)]
+pub struct Abs;
+
+impl Function for Abs {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let value = args_1::<Number>("abs", args, None)?;
+        Ok(Compiled(C::Constant(value.abs().into())))
+    }
+}
+
+/// The `sign` SQL function.
+#[derive(Debug)]
+pub struct Sign;
+
+impl Function for Sign {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let value = args_1::<Number>("sign", args, None)?;
+        Ok(Compiled(C::Constant(value.signum().into())))
+    }
+}
+
+/// The integral rounding (`floor`, `ceil`, `trunc`) SQL functions.
+#[derive(Debug)]
+pub enum Integral {
+    /// Rounds towards negative infinity (`floor`).
+    Floor,
+    /// Rounds towards positive infinity (`ceil`).
+    Ceil,
+    /// Rounds towards zero (`trunc`).
+    Trunc,
+}
+
+impl Function for Integral {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (name, func): (_, fn(f64) -> f64) = match self {
+            Self::Floor => ("floor", f64::floor),
+            Self::Ceil => ("ceil", f64::ceil),
+            Self::Trunc => ("trunc", f64::trunc),
+        };
+        let value = args_1::<Number>(name, args, None)?;
+        Ok(Compiled(C::Constant(value.round_with(func).into())))
+    }
+}
+
+/// The `power` SQL function.
+#[derive(Debug)]
+pub struct Power;
+
+impl Function for Power {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (base, exponent) = args_2::<Number, Number>("power", args, None, None)?;
+        Ok(Compiled(C::Constant(base.pow(&exponent).into())))
+    }
+}
+
+/// The unary floating-point SQL functions (`sqrt`, `exp`, `ln`, `sin`, etc).
+#[derive(Debug)]
+pub enum Float {
+    /// Square root (`sqrt`).
+    Sqrt,
+    /// Natural exponential (`exp`).
+    Exp,
+    /// Natural logarithm (`ln`).
+    Ln,
+    /// Base-10 logarithm (`log10`).
+    Log10,
+    /// Sine (`sin`).
+    Sin,
+    /// Cosine (`cos`).
+    Cos,
+    /// Tangent (`tan`).
+    Tan,
+    /// Inverse sine (`asin`).
+    Asin,
+    /// Inverse cosine (`acos`).
+    Acos,
+    /// Inverse tangent (`atan`).
+    Atan,
+}
+
+impl Float {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Sqrt => "sqrt",
+            Self::Exp => "exp",
+            Self::Ln => "ln",
+            Self::Log10 => "log10",
+            Self::Sin => "sin",
+            Self::Cos => "cos",
+            Self::Tan => "tan",
+            Self::Asin => "asin",
+            Self::Acos => "acos",
+            Self::Atan => "atan",
+        }
+    }
+}
+
+impl Function for Float {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let value = args_1::<f64>(self.name(), args, None)?;
+        let result = match self {
+            Self::Sqrt => (value >= 0.0).then(|| value.sqrt()),
+            Self::Exp => Some(value.exp()),
+            Self::Ln => (value > 0.0).then(|| value.ln()),
+            Self::Log10 => (value > 0.0).then(|| value.log10()),
+            Self::Sin => value.is_finite().then(|| value.sin()),
+            Self::Cos => value.is_finite().then(|| value.cos()),
+            Self::Tan => value.is_finite().then(|| value.tan()),
+            Self::Asin => (-1.0..=1.0).contains(&value).then(|| value.asin()),
+            Self::Acos => (-1.0..=1.0).contains(&value).then(|| value.acos()),
+            Self::Atan => Some(value.atan()),
+        };
         Ok(Compiled(C::Constant(result.into())))
     }
 }
 
+/// The `log` SQL function.
+#[derive(Debug)]
+pub struct Log;
+
+impl Function for Log {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (base, value) = args_2::<f64, f64>("log", args, None, None)?;
+        let result = value.log(base);
+        Ok(Compiled(C::Constant(
+            if base > 0.0 && value > 0.0 && result.is_finite() {
+                result.into()
+            } else {
+                Value::Null
+            },
+        )))
+    }
+}
+
+/// The `atan2` SQL function.
+#[derive(Debug)]
+pub struct Atan2;
+
+impl Function for Atan2 {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (y, x) = args_2::<f64, f64>("atan2", args, None, None)?;
+        Ok(Compiled(C::Constant(y.atan2(x).into())))
+    }
+}
+
+/// The `pi` SQL function.
+#[derive(Debug)]
+pub struct Pi;
+
+impl Function for Pi {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        require("pi", args.is_empty(), || "takes no arguments".to_owned())?;
+        Ok(Compiled(C::Constant(std::f64::consts::PI.into())))
+    }
+}
+
 //------------------------------------------------------------------------------
 
 /// The `div` SQL function.
@@ -2,13 +2,17 @@
 
 use super::{args_1, args_2, args_3, require, Arguments, Function};
 use crate::{
+    decimal::MAX_SCALE,
     error::Error,
     eval::{CompileContext, Compiled, C},
-    value::{Number, Value},
+    value::{DateOrTimestamp, Number, Value},
 };
-use rand::distributions::BernoulliError;
-use rand_distr::NormalError;
-use std::sync::Arc;
+use rand::{
+    distributions::{weighted::alias_method::WeightedIndex, BernoulliError, WeightedError},
+    Rng,
+};
+use rand_distr::{Distribution, NormalError, StandardNormal};
+use std::{convert::TryFrom, net::Ipv4Addr, sync::Arc};
 use zipf::ZipfDistribution;
 
 //------------------------------------------------------------------------------
@@ -116,6 +120,249 @@ impl Function for LogNormal {
 
 //------------------------------------------------------------------------------
 
+/// The `rand.normal` SQL function.
+#[derive(Debug)]
+pub struct Normal;
+
+impl Function for Normal {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.normal";
+        let (mean, std_dev) = args_2::<f64, f64>(name, args, None, None)?;
+        require(name, std_dev >= 0.0 && std_dev.is_finite(), || {
+            format!("standard deviation ({std_dev}) must be a finite number >= 0")
+        })?;
+        Ok(Compiled(C::RandNormal(
+            rand_distr::Normal::new(mean, std_dev).map_err(|NormalError::StdDevTooSmall| Error::InvalidArguments {
+                name,
+                cause: format!("standard deviation ({std_dev}) must >= 0"),
+            })?,
+        )))
+    }
+}
+
+/// The `rand.exponential` SQL function.
+#[derive(Debug)]
+pub struct Exponential;
+
+impl Function for Exponential {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.exponential";
+        let lambda = args_1::<f64>(name, args, None)?;
+        Ok(Compiled(C::RandExp(rand_distr::Exp::new(lambda).map_err(|_| {
+            Error::InvalidArguments {
+                name,
+                cause: format!("rate ({lambda}) must be positive"),
+            }
+        })?)))
+    }
+}
+
+/// The largest supported mean of `rand.poisson`. Larger means may produce samples beyond the range
+/// of `u64`.
+const MAX_POISSON_MEAN: f64 = 1e18;
+
+/// The `rand.poisson` SQL function.
+#[derive(Debug)]
+pub struct Poisson;
+
+impl Function for Poisson {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.poisson";
+        let lambda = args_1::<f64>(name, args, None)?;
+        require(name, lambda <= MAX_POISSON_MEAN, || {
+            format!("mean ({lambda}) must not exceed {MAX_POISSON_MEAN:e}")
+        })?;
+        Ok(Compiled(C::RandPoisson(rand_distr::Poisson::new(lambda).map_err(
+            |_| Error::InvalidArguments {
+                name,
+                cause: format!("mean ({lambda}) must be positive"),
+            },
+        )?)))
+    }
+}
+
+/// The `rand.binomial` SQL function.
+#[derive(Debug)]
+pub struct Binomial;
+
+impl Function for Binomial {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.binomial";
+        let (n, p) = args_2::<u64, f64>(name, args, None, None)?;
+        require(name, i64::try_from(n).is_ok(), || {
+            format!("number of trials ({n}) must not exceed {}", i64::MAX)
+        })?;
+        Ok(Compiled(C::RandBinomial(rand_distr::Binomial::new(n, p).map_err(
+            |_| Error::InvalidArguments {
+                name,
+                cause: format!("probability ({p}) must be inside [0, 1]"),
+            },
+        )?)))
+    }
+}
+
+#[test]
+fn test_distribution_parameters() {
+    use rand::SeedableRng;
+
+    let ctx = CompileContext::new(0);
+    let compile = |f: &dyn Function, args: Vec<Value>| f.compile(&ctx, args.into_iter().collect());
+    assert!(compile(&Normal, vec![0.into(), (-1.0).into()]).is_err());
+    assert!(compile(&Normal, vec![0.into(), f64::INFINITY.into()]).is_err());
+    assert!(compile(&Poisson, vec![1e20.into()]).is_err());
+    assert!(compile(&Poisson, vec![f64::INFINITY.into()]).is_err());
+    assert!(compile(&Poisson, vec![f64::NAN.into()]).is_err());
+    assert!(compile(&Binomial, vec![u64::MAX.into(), 0.5.into()]).is_err());
+    assert!(compile(&Binomial, vec![(1_u64 << 63).into(), 0.5.into()]).is_err());
+
+    // the largest accepted parameters must not panic while sampling.
+    let mut state = crate::eval::State::new(1, Box::new(rand_hc::Hc128Rng::seed_from_u64(1)), ctx.clone());
+    for args in vec![vec![MAX_POISSON_MEAN.into()], vec![i64::MAX.into(), 0.5.into()]] {
+        let f: &dyn Function = if args.len() == 1 { &Poisson } else { &Binomial };
+        let compiled = compile(f, args).unwrap();
+        for _ in 0..1000 {
+            compiled.eval(&mut state).unwrap();
+        }
+    }
+}
+
+/// The `rand.geometric` SQL function.
+#[derive(Debug)]
+pub struct Geometric;
+
+impl Function for Geometric {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.geometric";
+        let p = args_1::<f64>(name, args, None)?;
+        require(name, 0.0 < p && p <= 1.0, || {
+            format!("probability ({p}) must be inside (0, 1]")
+        })?;
+        Ok(Compiled(C::RandGeometric(GeometricDistribution {
+            inv_ln_q: (-p).ln_1p().recip(),
+        })))
+    }
+}
+
+/// The geometric distribution, counting the number of failures before the first success.
+#[derive(Copy, Clone, Debug)]
+pub struct GeometricDistribution {
+    /// The reciprocal of ln(1 − *p*). This is −0.0 when *p* = 1.
+    inv_ln_q: f64,
+}
+
+impl Distribution<u64> for GeometricDistribution {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        // `1 - gen()` is inside (0, 1], so the logarithm is finite and non-positive.
+        let u = 1.0 - rng.gen::<f64>();
+        (u.ln() * self.inv_ln_q).floor() as u64
+    }
+}
+
+/// The `rand.gamma` SQL function.
+#[derive(Debug)]
+pub struct Gamma;
+
+impl Function for Gamma {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.gamma";
+        let (shape, scale) = args_2::<f64, f64>(name, args, None, Some(1.0))?;
+        Ok(Compiled(C::RandGamma(rand_distr::Gamma::new(shape, scale).map_err(
+            |_| Error::InvalidArguments {
+                name,
+                cause: format!("shape ({shape}) and scale ({scale}) must be positive"),
+            },
+        )?)))
+    }
+}
+
+/// The `rand.beta` SQL function.
+#[derive(Debug)]
+pub struct Beta;
+
+impl Function for Beta {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.beta";
+        let (alpha, beta) = args_2::<f64, f64>(name, args, None, None)?;
+        Ok(Compiled(C::RandBeta(Box::new(
+            rand_distr::Beta::new(alpha, beta).map_err(|_| Error::InvalidArguments {
+                name,
+                cause: format!("alpha ({alpha}) and beta ({beta}) must be positive"),
+            })?,
+        ))))
+    }
+}
+
+/// The `rand.weibull` SQL function.
+#[derive(Debug)]
+pub struct Weibull;
+
+impl Function for Weibull {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.weibull";
+        let (scale, shape) = args_2::<f64, f64>(name, args, None, None)?;
+        Ok(Compiled(C::RandWeibull(
+            rand_distr::Weibull::new(scale, shape).map_err(|_| Error::InvalidArguments {
+                name,
+                cause: format!("scale ({scale}) and shape ({shape}) must be positive"),
+            })?,
+        )))
+    }
+}
+
+/// The `rand.pareto` SQL function.
+#[derive(Debug)]
+pub struct Pareto;
+
+impl Function for Pareto {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.pareto";
+        let (scale, shape) = args_2::<f64, f64>(name, args, None, None)?;
+        Ok(Compiled(C::RandPareto(rand_distr::Pareto::new(scale, shape).map_err(
+            |_| Error::InvalidArguments {
+                name,
+                cause: format!("scale ({scale}) and shape ({shape}) must be positive"),
+            },
+        )?)))
+    }
+}
+
+/// The `rand.triangular` SQL function.
+#[derive(Debug)]
+pub struct Triangular;
+
+impl Function for Triangular {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.triangular";
+        let (min, max, mode) = args_3::<f64, f64, f64>(name, args, None, None, None)?;
+        Ok(Compiled(C::RandTriangular(
+            rand_distr::Triangular::new(min, max, mode).map_err(|_| Error::InvalidArguments {
+                name,
+                cause: format!("must have min ({min}) <= mode ({mode}) <= max ({max})"),
+            })?,
+        )))
+    }
+}
+
+/// The `rand.cauchy` SQL function.
+#[derive(Debug)]
+pub struct Cauchy;
+
+impl Function for Cauchy {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.cauchy";
+        let (median, scale) = args_2::<f64, f64>(name, args, None, None)?;
+        Ok(Compiled(C::RandCauchy(
+            rand_distr::Cauchy::new(median, scale).map_err(|_| Error::InvalidArguments {
+                name,
+                cause: format!("scale ({scale}) must be positive"),
+            })?,
+        )))
+    }
+}
+
+//------------------------------------------------------------------------------
+
 /// The `rand.bool` SQL function.
 #[derive(Debug)]
 pub struct Bool;
@@ -133,6 +380,28 @@ impl Function for Bool {
     }
 }
 
+/// The `rand.decimal` SQL function.
+#[derive(Debug)]
+pub struct Decimal;
+
+impl Function for Decimal {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.decimal";
+        let (precision, scale) = args_2::<u8, u8>(name, args, None, Some(0))?;
+        require(name, 0 < precision && precision <= MAX_SCALE, || {
+            format!("precision ({precision}) must be between 1 and {MAX_SCALE}")
+        })?;
+        require(name, scale <= precision, || {
+            format!("scale ({scale}) must not exceed the precision ({precision})")
+        })?;
+        let limit = 10_i128.pow(precision.into()) - 1;
+        Ok(Compiled(C::RandDecimal {
+            mantissa: rand_distr::Uniform::new_inclusive(-limit, limit),
+            scale,
+        }))
+    }
+}
+
 //------------------------------------------------------------------------------
 
 /// The `rand.finite_f32` SQL function.
@@ -180,6 +449,260 @@ impl Function for Uuid {
 
 //------------------------------------------------------------------------------
 
+/// The `rand.ipv4` SQL function.
+#[derive(Debug)]
+pub struct Ipv4;
+
+/// The `rand.ipv4_in` SQL function.
+#[derive(Debug)]
+pub struct Ipv4In;
+
+/// The `rand.ipv6` SQL function.
+#[derive(Debug)]
+pub struct Ipv6;
+
+/// The `rand.mac` SQL function.
+#[derive(Debug)]
+pub struct Mac;
+
+/// Parses an IPv4 CIDR block like `10.0.0.0/8` into the inclusive range of addresses it covers.
+/// The host bits of the address are ignored. A plain address without the prefix length is treated
+/// as a `/32` block.
+fn parse_ipv4_cidr(name: &'static str, cidr: &str) -> Result<(u32, u32), Error> {
+    let (addr, prefix_len) = match cidr.find('/') {
+        Some(slash) => (&cidr[..slash], cidr[slash + 1..].parse::<u32>().ok()),
+        None => (cidr, Some(32)),
+    };
+    let (addr, prefix_len) = match (addr.parse::<Ipv4Addr>(), prefix_len) {
+        (Ok(addr), Some(prefix_len)) if prefix_len <= 32 => (u32::from(addr), prefix_len),
+        _ => {
+            return Err(Error::InvalidArguments {
+                name,
+                cause: format!("invalid IPv4 CIDR block '{cidr}'"),
+            })
+        }
+    };
+    let host_mask = u32::MAX.checked_shr(prefix_len).unwrap_or(0);
+    Ok((addr & !host_mask, addr | host_mask))
+}
+
+impl Function for Ipv4 {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let binary = args_1::<Option<bool>>("rand.ipv4", args, Some(None))?.unwrap_or(false);
+        Ok(Compiled(C::RandIpv4 {
+            range: rand_distr::Uniform::new_inclusive(0, u32::MAX),
+            binary,
+        }))
+    }
+}
+
+impl Function for Ipv4In {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.ipv4_in";
+        let (cidr, binary) = args_2::<String, Option<bool>>(name, args, None, Some(None))?;
+        let (start, end) = parse_ipv4_cidr(name, &cidr)?;
+        Ok(Compiled(C::RandIpv4 {
+            range: rand_distr::Uniform::new_inclusive(start, end),
+            binary: binary.unwrap_or(false),
+        }))
+    }
+}
+
+impl Function for Ipv6 {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let binary = args_1::<Option<bool>>("rand.ipv6", args, Some(None))?.unwrap_or(false);
+        Ok(Compiled(C::RandIpv6 { binary }))
+    }
+}
+
+impl Function for Mac {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let binary = args_1::<Option<bool>>("rand.mac", args, Some(None))?.unwrap_or(false);
+        Ok(Compiled(C::RandMac { binary }))
+    }
+}
+
+/// Formats a MAC address as six colon-separated pairs of lowercase hex digits.
+pub(crate) fn format_mac(mac: [u8; 6]) -> String {
+    format!(
+        "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+        mac[0], mac[1], mac[2], mac[3], mac[4], mac[5]
+    )
+}
+
+//------------------------------------------------------------------------------
+
+/// Milliseconds since 1970-01-01 of the Snowflake epoch (2010-11-04T01:42:54.657Z).
+const SNOWFLAKE_EPOCH_MS: i64 = 1_288_834_974_657;
+
+/// Seconds since 1970-01-01 of the KSUID epoch (2014-05-13T16:53:20Z).
+const KSUID_EPOCH_SECS: i64 = 1_400_000_000;
+
+/// Extracts the timestamp argument of the version 7 UUID and ULID functions, and converts it to the
+/// number of milliseconds since the Unix epoch, which must fit in 48 bits.
+fn unix_millis_48(ctx: &CompileContext, name: &'static str, args: Arguments) -> Result<u64, Error> {
+    let (ts, _) = args_1::<DateOrTimestamp>(name, args, None)?.to_timestamp(ctx.time_zone)?;
+    let millis = ts.and_utc().timestamp_millis();
+    u64::try_from(millis)
+        .ok()
+        .filter(|m| *m < 1 << 48)
+        .ok_or_else(|| Error::InvalidArguments {
+            name,
+            cause: format!("timestamp ({millis} ms) must fit in 48 bits after the Unix epoch"),
+        })
+}
+
+/// The `uuid_v7` SQL function.
+#[derive(Debug)]
+pub struct UuidV7;
+
+impl Function for UuidV7 {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let millis = unix_millis_48(ctx, "uuid_v7", args)?;
+        Ok(Compiled(C::RandTimeOrderedId(TimeOrderedId::UuidV7 { millis })))
+    }
+}
+
+/// The `ulid` SQL function.
+#[derive(Debug)]
+pub struct Ulid;
+
+impl Function for Ulid {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let millis = unix_millis_48(ctx, "ulid", args)?;
+        Ok(Compiled(C::RandTimeOrderedId(TimeOrderedId::Ulid { millis })))
+    }
+}
+
+/// The `ksuid` SQL function.
+#[derive(Debug)]
+pub struct Ksuid;
+
+impl Function for Ksuid {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "ksuid";
+        let (ts, _) = args_1::<DateOrTimestamp>(name, args, None)?.to_timestamp(ctx.time_zone)?;
+        let secs = ts.and_utc().timestamp() - KSUID_EPOCH_SECS;
+        let secs = u32::try_from(secs).map_err(|_| Error::InvalidArguments {
+            name,
+            cause: format!("timestamp ({secs} s) must fit in 32 bits after the KSUID epoch"),
+        })?;
+        Ok(Compiled(C::RandTimeOrderedId(TimeOrderedId::Ksuid { secs })))
+    }
+}
+
+/// The `snowflake` SQL function.
+#[derive(Debug)]
+pub struct Snowflake;
+
+impl Function for Snowflake {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "snowflake";
+        let (input, worker_id, sequence) = args_3::<DateOrTimestamp, u64, u64>(name, args, None, None, None)?;
+        let (ts, _) = input.to_timestamp(ctx.time_zone)?;
+        let millis = ts.and_utc().timestamp_millis() - SNOWFLAKE_EPOCH_MS;
+        require(name, (0..1 << 41).contains(&millis), || {
+            format!("timestamp ({millis} ms) must fit in 41 bits after the Snowflake epoch")
+        })?;
+        require(name, worker_id < 1 << 10, || {
+            format!("worker ID ({worker_id}) must be below 1024")
+        })?;
+        require(name, sequence < 1 << 12, || {
+            format!("sequence ({sequence}) must be below 4096")
+        })?;
+        #[allow(clippy::cast_sign_loss)]
+        let id = (millis as u64) << 22 | worker_id << 12 | sequence;
+        Ok(Compiled(C::Constant(id.into())))
+    }
+}
+
+/// A time-ordered identifier with the given timestamp and a random suffix, sampled as a string.
+#[derive(Copy, Clone, Debug)]
+pub enum TimeOrderedId {
+    /// A version 7 UUID.
+    UuidV7 {
+        /// Milliseconds since the Unix epoch (48 bits).
+        millis: u64,
+    },
+    /// A ULID.
+    Ulid {
+        /// Milliseconds since the Unix epoch (48 bits).
+        millis: u64,
+    },
+    /// A KSUID.
+    Ksuid {
+        /// Seconds since the KSUID epoch.
+        secs: u32,
+    },
+}
+
+/// The Crockford's Base32 alphabet used by ULID.
+const CROCKFORD_BASE32: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// The Base62 alphabet used by KSUID.
+const BASE62: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+impl TimeOrderedId {
+    /// Generates a random identifier.
+    pub(crate) fn sample<R: Rng + ?Sized>(self, rng: &mut R) -> String {
+        match self {
+            Self::UuidV7 { millis } => sample_uuid_v7(millis, rng),
+            Self::Ulid { millis } => sample_ulid(millis, rng),
+            Self::Ksuid { secs } => sample_ksuid(secs, rng),
+        }
+    }
+}
+
+/// Generates a version 7 UUID.
+fn sample_uuid_v7<R: Rng + ?Sized>(millis: u64, rng: &mut R) -> String {
+    let r = rng.gen::<u128>();
+    let bits =
+        u128::from(millis) << 80 | 0x7 << 76 | (r & 0xfff) << 64 | 0b10 << 62 | (r >> 64) & 0x3fff_ffff_ffff_ffff;
+    let hex = format!("{bits:032x}");
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+/// Generates a ULID.
+fn sample_ulid<R: Rng + ?Sized>(millis: u64, rng: &mut R) -> String {
+    let bits = u128::from(millis) << 80 | rng.gen::<u128>() >> 48;
+    (0..26)
+        .rev()
+        .map(|i| char::from(CROCKFORD_BASE32[(bits >> (i * 5)) as usize & 31]))
+        .collect()
+}
+
+/// Generates a KSUID.
+fn sample_ksuid<R: Rng + ?Sized>(secs: u32, rng: &mut R) -> String {
+    let mut bytes = [0; 20];
+    bytes[..4].copy_from_slice(&secs.to_be_bytes());
+    rng.fill(&mut bytes[4..]);
+
+    // the 160-bit number is converted to base 62 by repeated long division.
+    let mut digits = [0; 27];
+    for digit in digits.iter_mut().rev() {
+        let mut remainder = 0;
+        for byte in &mut bytes {
+            let acc = remainder << 8 | u32::from(*byte);
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                *byte = (acc / 62) as u8;
+            }
+            remainder = acc % 62;
+        }
+        *digit = BASE62[remainder as usize];
+    }
+    String::from_utf8(digits.to_vec()).unwrap()
+}
+
+//------------------------------------------------------------------------------
+
 /// The `rand.regex` SQL function.
 #[derive(Debug)]
 pub struct Regex;
@@ -222,6 +745,68 @@ fn compile_regex_generator(regex: &str, flags: &str, max_repeat: u32) -> Result<
 
 //------------------------------------------------------------------------------
 
+/// The `rand.bytes` and `rand.text` SQL functions.
+#[derive(Debug)]
+pub struct Compressible {
+    /// Function name.
+    pub name: &'static str,
+    /// Whether to generate printable ASCII text instead of arbitrary bytes.
+    pub text: bool,
+}
+
+impl Function for Compressible {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (len, compress_ratio) = args_2::<usize, f64>(self.name, args, None, Some(1.0))?;
+        require(self.name, 0.0 < compress_ratio && compress_ratio <= 1.0, || {
+            format!("compress_ratio ({compress_ratio}) must be in the range (0, 1]")
+        })?;
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        let unique_len = ((len as f64 * compress_ratio).ceil() as usize).clamp(1, len.max(1));
+        Ok(Compiled(C::RandCompressible(CompressiblePayload {
+            len,
+            unique_len,
+            text: self.text,
+        })))
+    }
+}
+
+/// Generates payloads with a target compression ratio, by generating a random prefix and repeating
+/// it until the full length is reached (the same approach as `db_bench`).
+#[derive(Clone, Debug)]
+pub struct CompressiblePayload {
+    /// Total length of the payload.
+    len: usize,
+    /// Length of the random prefix.
+    unique_len: usize,
+    /// Whether to generate printable ASCII text instead of arbitrary bytes.
+    text: bool,
+}
+
+impl CompressiblePayload {
+    /// Generates a payload.
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Value {
+        let unique = if self.text {
+            rng.sample_iter(rand_distr::Uniform::new_inclusive(b' ', b'~'))
+                .take(self.unique_len)
+                .collect::<Vec<_>>()
+        } else {
+            rng.sample_iter(rand_distr::Standard).take(self.unique_len).collect()
+        };
+        let payload = unique.into_iter().cycle().take(self.len).collect::<Vec<_>>();
+        if self.text {
+            String::from_utf8(payload).unwrap().into()
+        } else {
+            Value::new_binary(payload)
+        }
+    }
+}
+
+//------------------------------------------------------------------------------
+
 /// The `rand.shuffle` SQL function.
 #[derive(Debug)]
 pub struct Shuffle;
@@ -232,3 +817,425 @@ impl Function for Shuffle {
         Ok(Compiled(C::RandShuffle(array)))
     }
 }
+
+//------------------------------------------------------------------------------
+
+/// The `rand.choice` SQL function.
+#[derive(Debug)]
+pub struct Choice;
+
+impl Function for Choice {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.choice";
+        let array = args_1::<Arc<[Value]>>(name, args, None)?;
+        require(name, !array.is_empty(), || {
+            "cannot choose from an empty array".to_owned()
+        })?;
+        let index = rand_distr::Uniform::new(0, array.len());
+        Ok(Compiled(C::RandChoice { array, index }))
+    }
+}
+
+/// Converts every element of the array into a floating point number.
+fn numbers_from_array(name: &'static str, what: &str, array: &[Value]) -> Result<Vec<f64>, Error> {
+    array
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            f64::try_from(value.clone()).map_err(|_| Error::InvalidArguments {
+                name,
+                cause: format!("{} #{} is not a number", what, i + 1),
+            })
+        })
+        .collect()
+}
+
+/// Builds the alias table for choosing indices with the given weights.
+fn weighted_index(name: &'static str, weights: Vec<f64>) -> Result<WeightedIndex<f64>, Error> {
+    WeightedIndex::new(weights).map_err(|e| Error::InvalidArguments {
+        name,
+        cause: match e {
+            WeightedError::NoItem => "cannot choose from an empty array".to_owned(),
+            WeightedError::AllWeightsZero => "weights cannot be all zero".to_owned(),
+            WeightedError::InvalidWeight => "weights must be finite non-negative numbers".to_owned(),
+            WeightedError::TooMany => format!("cannot choose from more than {} items", u32::MAX),
+        },
+    })
+}
+
+/// The `rand.weighted` SQL function.
+#[derive(Debug)]
+pub struct Weighted;
+
+impl Function for Weighted {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.weighted";
+        let (array, weights) = args_2::<Arc<[Value]>, Arc<[Value]>>(name, args, None, None)?;
+        require(name, array.len() == weights.len(), || {
+            format!(
+                "number of values ({}) and weights ({}) must be equal",
+                array.len(),
+                weights.len()
+            )
+        })?;
+        let weights = numbers_from_array(name, "weight", &weights)?;
+        let index = weighted_index(name, weights)?;
+        Ok(Compiled(C::RandWeighted {
+            array,
+            index: Box::new(index),
+        }))
+    }
+}
+
+/// The `rand.histogram` SQL function.
+#[derive(Debug)]
+pub struct Histogram;
+
+/// The `rand.quantiles` SQL function.
+#[derive(Debug)]
+pub struct Quantiles;
+
+/// Checks that the bucket bounds are finite and sorted.
+fn check_bounds(name: &'static str, bounds: &[f64]) -> Result<(), Error> {
+    require(name, bounds.len() >= 2, || "expecting at least 2 bounds".to_owned())?;
+    require(name, bounds.iter().all(|b| b.is_finite()), || {
+        "bounds must be finite numbers".to_owned()
+    })?;
+    require(name, bounds.windows(2).all(|w| w[0] <= w[1]), || {
+        "bounds must be sorted in ascending order".to_owned()
+    })
+}
+
+impl Function for Histogram {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.histogram";
+        let (bounds, counts) = args_2::<Arc<[Value]>, Arc<[Value]>>(name, args, None, None)?;
+        let bounds = numbers_from_array(name, "bound", &bounds)?;
+        check_bounds(name, &bounds)?;
+        require(name, bounds.len() == counts.len() + 1, || {
+            format!(
+                "number of bounds ({}) must be one more than number of counts ({})",
+                bounds.len(),
+                counts.len()
+            )
+        })?;
+        let counts = numbers_from_array(name, "count", &counts)?;
+        let buckets = weighted_index(name, counts)?;
+        Ok(Compiled(C::RandHistogram(Box::new(HistogramDistribution {
+            bounds,
+            buckets,
+        }))))
+    }
+}
+
+impl Function for Quantiles {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.quantiles";
+        let quantiles = args_1::<Arc<[Value]>>(name, args, None)?;
+        let bounds = numbers_from_array(name, "quantile", &quantiles)?;
+        check_bounds(name, &bounds)?;
+        let buckets = weighted_index(name, vec![1.0; bounds.len() - 1])?;
+        Ok(Compiled(C::RandHistogram(Box::new(HistogramDistribution {
+            bounds,
+            buckets,
+        }))))
+    }
+}
+
+/// A piecewise uniform distribution. Bucket *i* covers the half-open interval between
+/// `bounds[i]` and `bounds[i + 1]`, and is chosen with probability proportional to its weight.
+#[derive(Clone, Debug)]
+pub struct HistogramDistribution {
+    /// The bucket bounds in ascending order. This has one more element than the number of buckets.
+    bounds: Vec<f64>,
+    /// Alias table of the bucket indices.
+    buckets: WeightedIndex<f64>,
+}
+
+impl Distribution<f64> for HistogramDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let i = self.buckets.sample(rng);
+        let (lower, upper) = (self.bounds[i], self.bounds[i + 1]);
+        lower + (upper - lower) * rng.gen::<f64>()
+    }
+}
+
+/// The `rand.sample` SQL function.
+#[derive(Debug)]
+pub struct Sample;
+
+impl Function for Sample {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.sample";
+        let (array, amount) = args_2::<Arc<[Value]>, usize>(name, args, None, None)?;
+        require(name, amount <= array.len(), || {
+            format!("cannot sample {} elements from an array of {}", amount, array.len())
+        })?;
+        Ok(Compiled(C::RandSample { array, amount }))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `rand.mvnormal` SQL function.
+#[derive(Debug)]
+pub struct MvNormal;
+
+/// The `rand.copula` SQL function.
+#[derive(Debug)]
+pub struct Copula;
+
+/// Reads a square matrix given as an array of row arrays.
+fn square_matrix(name: &'static str, rows: &[Value]) -> Result<Vec<Vec<f64>>, Error> {
+    let n = rows.len();
+    require(name, n > 0, || "the matrix must not be empty".to_owned())?;
+    rows.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let row = Arc::<[Value]>::try_from(row.clone()).map_err(|_| Error::InvalidArguments {
+                name,
+                cause: format!("row #{} of the matrix is not an array", i + 1),
+            })?;
+            require(name, row.len() == n, || {
+                format!(
+                    "row #{} of the matrix has {} elements, expecting {}",
+                    i + 1,
+                    row.len(),
+                    n
+                )
+            })?;
+            numbers_from_array(name, "matrix element", &row)
+        })
+        .collect()
+}
+
+/// Computes the lower triangular matrix *L* with *LL*<sup>T</sup> = *A* using the Cholesky
+/// decomposition, where *A* is a symmetric positive semi-definite matrix. The result is stored
+/// in row-major order.
+fn cholesky(name: &'static str, a: &[Vec<f64>]) -> Result<Vec<f64>, Error> {
+    let n = a.len();
+    let scale = a.iter().enumerate().map(|(i, row)| row[i].abs()).fold(0.0, f64::max);
+    let tolerance = scale * 1e-9;
+    for (i, row) in a.iter().enumerate() {
+        for (j, a_ij) in row.iter().enumerate().take(i) {
+            require(name, (a_ij - a[j][i]).abs() <= tolerance, || {
+                format!("the covariance matrix is not symmetric at ({}, {})", i + 1, j + 1)
+            })?;
+        }
+    }
+
+    let mut l = vec![0.0; n * n];
+    for (j, row_j) in a.iter().enumerate() {
+        let diagonal = row_j[j] - (0..j).map(|k| l[j * n + k] * l[j * n + k]).sum::<f64>();
+        require(name, diagonal >= -tolerance, || {
+            "the covariance matrix is not positive semi-definite".to_owned()
+        })?;
+        if diagonal <= tolerance {
+            // this variable is a linear combination of the previous ones; its column stays zero.
+            continue;
+        }
+        let l_jj = diagonal.sqrt();
+        l[j * n + j] = l_jj;
+        for (i, row_i) in a.iter().enumerate().skip(j + 1) {
+            let dot = (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum::<f64>();
+            l[i * n + j] = (row_i[j] - dot) / l_jj;
+        }
+    }
+    Ok(l)
+}
+
+impl Function for MvNormal {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.mvnormal";
+        let (means, covariance) = args_2::<Arc<[Value]>, Arc<[Value]>>(name, args, None, None)?;
+        let means = numbers_from_array(name, "mean", &means)?;
+        let covariance = square_matrix(name, &covariance)?;
+        require(name, means.len() == covariance.len(), || {
+            format!(
+                "number of means ({}) and size of the covariance matrix ({}) must be equal",
+                means.len(),
+                covariance.len()
+            )
+        })?;
+        let lower = cholesky(name, &covariance)?;
+        Ok(Compiled(C::RandMvNormal(Box::new(MultivariateNormal {
+            means,
+            lower,
+            copula: false,
+        }))))
+    }
+}
+
+impl Function for Copula {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.copula";
+        let correlation = args_1::<Arc<[Value]>>(name, args, None)?;
+        let correlation = square_matrix(name, &correlation)?;
+        let lower = cholesky(name, &correlation)?;
+        Ok(Compiled(C::RandMvNormal(Box::new(MultivariateNormal {
+            means: vec![0.0; correlation.len()],
+            lower,
+            copula: true,
+        }))))
+    }
+}
+
+/// The multivariate normal distribution, optionally transformed into a Gaussian copula.
+#[derive(Clone, Debug)]
+pub struct MultivariateNormal {
+    /// The mean vector.
+    means: Vec<f64>,
+    /// The Cholesky factor of the covariance matrix, in row-major order.
+    lower: Vec<f64>,
+    /// Whether to transform every component to a uniform number in [0, 1] using its own
+    /// cumulative distribution function.
+    copula: bool,
+}
+
+/// The cumulative distribution function of the standard normal distribution.
+///
+/// This uses the Chebyshev approximation of erfc from *Numerical Recipes*, having a fractional
+/// error less than 1.2 × 10<sup>−7</sup>.
+fn standard_normal_cdf(x: f64) -> f64 {
+    let z = x.abs() * std::f64::consts::FRAC_1_SQRT_2;
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = [
+        -1.265_512_23,
+        1.000_023_68,
+        0.374_091_96,
+        0.096_784_18,
+        -0.186_288_06,
+        0.278_868_07,
+        -1.135_203_98,
+        1.488_515_87,
+        -0.822_152_23,
+        0.170_872_77,
+    ]
+    .iter()
+    .rev()
+    .fold(0.0, |acc, c| acc * t + c);
+    let erfc = t * (-z * z + poly).exp();
+    if x >= 0.0 {
+        1.0 - 0.5 * erfc
+    } else {
+        0.5 * erfc
+    }
+}
+
+impl Distribution<Vec<f64>> for MultivariateNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let n = self.means.len();
+        let z = (0..n).map(|_| rng.sample(StandardNormal)).collect::<Vec<f64>>();
+        (0..n)
+            .map(|i| {
+                let row = &self.lower[i * n..=i * n + i];
+                let x = row.iter().zip(&z).map(|(l, z)| l * z).sum::<f64>();
+                if self.copula {
+                    let std_dev = row.iter().map(|l| l * l).sum::<f64>().sqrt();
+                    if std_dev > 0.0 {
+                        standard_normal_cdf(x / std_dev)
+                    } else {
+                        0.5
+                    }
+                } else {
+                    self.means[i] + x
+                }
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_cholesky() {
+    let l = cholesky("test", &[vec![4.0, 2.0], vec![2.0, 10.0]]).unwrap();
+    assert_eq!(l, vec![2.0, 0.0, 1.0, 3.0]);
+    // perfectly correlated variables are positive semi-definite.
+    let l = cholesky("test", &[vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+    assert_eq!(l, vec![1.0, 0.0, 1.0, 0.0]);
+    assert!(cholesky("test", &[vec![1.0, 2.0], vec![2.0, 1.0]]).is_err());
+    assert!(cholesky("test", &[vec![1.0, 0.5], vec![0.0, 1.0]]).is_err());
+
+    assert!((standard_normal_cdf(0.0) - 0.5).abs() < 1e-7);
+    assert!((standard_normal_cdf(1.959_964) - 0.975).abs() < 1e-7);
+    assert!((standard_normal_cdf(-1.0) - 0.158_655_25).abs() < 1e-7);
+}
+
+//------------------------------------------------------------------------------
+
+/// The `rand.permute` SQL function.
+#[derive(Debug)]
+pub struct Permute;
+
+impl Function for Permute {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.permute";
+        let (n, x) = args_2::<u64, u64>(name, args, None, None)?;
+        require(name, 1 <= x && x <= n, || {
+            format!("position ({x}) must be inside [1, {n}]")
+        })?;
+        Ok(Compiled(C::Constant(Value::Number(
+            (permute(&ctx.seed, n, x - 1) + 1).into(),
+        ))))
+    }
+}
+
+/// Number of Feistel rounds used by `permute`.
+const PERMUTE_ROUNDS: u64 = 8;
+
+/// The finalizer of the `splitmix64` generator, used as the Feistel round function.
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Maps `x` in the range `0..n` to a unique position in the same range, keyed by the `seed`.
+///
+/// This is a balanced Feistel network over the smallest even number of bits covering `n`. Since
+/// the network permutes a domain at most 4 times larger than `n`, we use cycle walking (feeding the
+/// output back in until it falls inside the range) to restrict it to a bijection on `0..n`.
+fn permute(seed: &[u8; 32], n: u64, mut x: u64) -> u64 {
+    let mut keys = [0; 4];
+    for (key, chunk) in keys.iter_mut().zip(seed.chunks_exact(8)) {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(chunk);
+        *key = u64::from_le_bytes(bytes) ^ n;
+    }
+
+    let bits = (64 - (n - 1).leading_zeros() + 1).max(2) & !1;
+    let half_bits = bits / 2;
+    let half_mask = u64::MAX >> (64 - half_bits);
+
+    loop {
+        let mut left = x >> half_bits;
+        let mut right = x & half_mask;
+        for round in 0..PERMUTE_ROUNDS {
+            #[allow(clippy::cast_possible_truncation)]
+            let key = keys[(round % 4) as usize].wrapping_add(round);
+            let f = mix64(right ^ key) & half_mask;
+            let new_right = left ^ f;
+            left = right;
+            right = new_right;
+        }
+        x = (left << half_bits) | right;
+        if x < n {
+            return x;
+        }
+    }
+}
+
+#[test]
+fn test_permute_is_bijective() {
+    let seed = [0x55; 32];
+    for &n in &[1, 2, 3, 4, 5, 17, 100, 1000, 4097] {
+        let mut seen = vec![false; n as usize];
+        for x in 0..n {
+            let y = permute(&seed, n, x);
+            assert!(y < n, "permute({}, {}) = {}", n, x, y);
+            assert!(!seen[y as usize], "permute({}, {}) = {} is repeated", n, x, y);
+            seen[y as usize] = true;
+        }
+    }
+    assert_ne!(permute(&seed, 1000, 1), permute(&[0xaa; 32], 1000, 1));
+    assert!(permute(&seed, u64::MAX, u64::MAX - 1) < u64::MAX);
+}
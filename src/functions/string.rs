@@ -1,12 +1,21 @@
 //! String functions.
 
-use super::{args_1, args_3, args_4, Arguments, Function};
+use super::{args_1, args_2, args_3, args_4, require, Arguments, Function};
 use crate::{
     error::Error,
     eval::{CompileContext, Compiled, C},
     value::Value,
 };
-use std::{convert::TryInto, isize};
+use std::{
+    convert::{TryFrom, TryInto},
+    isize,
+    iter::once,
+    str::from_utf8,
+};
+
+/// The maximum length of a string produced by `lpad`, `rpad` and `repeat` (2<sup>30</sup>). The
+/// length is counted in bytes for `repeat`, and in the unit of the string for `lpad` and `rpad`.
+const MAX_STRING_LENGTH: usize = 1 << 30;
 
 //------------------------------------------------------------------------------
 
@@ -61,6 +70,55 @@ impl Unit {
             Self::Characters => input.iter().filter(|b| is_utf8_leading_byte(**b)).count(),
         }
     }
+
+    /// Splits the input into slices each containing exactly one unit.
+    fn split(self, input: &[u8]) -> Vec<&[u8]> {
+        match self {
+            Self::Octets => input.chunks(1).collect(),
+            Self::Characters => {
+                let mut result = Vec::with_capacity(input.len());
+                let mut start = 0;
+                for (i, b) in input.iter().enumerate().skip(1) {
+                    if is_utf8_leading_byte(*b) {
+                        result.push(&input[start..i]);
+                        start = i;
+                    }
+                }
+                if start < input.len() {
+                    result.push(&input[start..]);
+                }
+                result
+            }
+        }
+    }
+
+    /// Computes the byte index after skipping `count` units of the input.
+    fn byte_index(self, input: &[u8], count: usize) -> usize {
+        match self {
+            Self::Octets => count.min(input.len()),
+            Self::Characters => input
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| is_utf8_leading_byte(**b))
+                .nth(count)
+                .map_or(input.len(), |(i, _)| i),
+        }
+    }
+}
+
+#[test]
+fn test_split_units() {
+    let b = "ßs≠🥰".as_bytes();
+    assert_eq!(
+        Unit::Characters.split(b),
+        vec!["ß".as_bytes(), b"s", "≠".as_bytes(), "🥰".as_bytes()]
+    );
+    assert_eq!(Unit::Octets.split(b"abc"), vec![b"a", b"b", b"c"]);
+    assert_eq!(Unit::Characters.byte_index(b, 0), 0);
+    assert_eq!(Unit::Characters.byte_index(b, 2), 3);
+    assert_eq!(Unit::Characters.byte_index(b, 4), 10);
+    assert_eq!(Unit::Characters.byte_index(b, 99), 10);
+    assert_eq!(Unit::Octets.byte_index(b, 2), 2);
 }
 
 #[test]
@@ -182,3 +240,326 @@ impl Function for Concat {
         Ok(Compiled(C::Constant(result)))
     }
 }
+
+//------------------------------------------------------------------------------
+
+/// The case conversion SQL functions.
+#[derive(Debug)]
+pub enum ChangeCase {
+    /// The `upper` SQL function.
+    Upper,
+    /// The `lower` SQL function.
+    Lower,
+    /// The `initcap` SQL function.
+    InitCap,
+}
+
+impl ChangeCase {
+    /// Converts the case of a character. `prev_is_alphanumeric` is only used by `initcap`, where the
+    /// first letter of each word is converted to uppercase and the rest to lowercase.
+    fn convert(&self, c: char, prev_is_alphanumeric: bool, output: &mut String) {
+        let upper = match self {
+            Self::Upper => true,
+            Self::Lower => false,
+            Self::InitCap => !prev_is_alphanumeric,
+        };
+        if upper {
+            output.extend(c.to_uppercase());
+        } else {
+            output.extend(c.to_lowercase());
+        }
+    }
+}
+
+impl Function for ChangeCase {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = match self {
+            Self::Upper => "upper",
+            Self::Lower => "lower",
+            Self::InitCap => "initcap",
+        };
+        let mut input = args_1::<Vec<u8>>(name, args, None)?;
+        let mut prev_is_alphanumeric = false;
+        if let Ok(s) = from_utf8(&input) {
+            let mut output = String::with_capacity(s.len());
+            for c in s.chars() {
+                self.convert(c, prev_is_alphanumeric, &mut output);
+                prev_is_alphanumeric = c.is_alphanumeric();
+            }
+            return Ok(Compiled(C::Constant(output.into())));
+        }
+
+        // binary strings are converted as ASCII.
+        for b in &mut input {
+            let mut output = String::with_capacity(1);
+            if b.is_ascii() {
+                self.convert(char::from(*b), prev_is_alphanumeric, &mut output);
+                *b = output.as_bytes()[0];
+            }
+            prev_is_alphanumeric = b.is_ascii_alphanumeric();
+        }
+        Ok(Compiled(C::Constant(input.into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `trim`, `ltrim`, `rtrim` and `btrim` SQL functions.
+#[derive(Debug)]
+pub struct Trim {
+    /// The string unit used by the function.
+    pub unit: Unit,
+    /// Whether to remove the characters from the start of the string.
+    pub leading: bool,
+    /// Whether to remove the characters from the end of the string.
+    pub trailing: bool,
+}
+
+impl Function for Trim {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = match (self.leading, self.trailing) {
+            (true, false) => "ltrim",
+            (false, true) => "rtrim",
+            _ => "trim",
+        };
+        let (input, characters) = args_2::<Vec<u8>, Vec<u8>>(name, args, None, Some(b" ".to_vec()))?;
+        let characters = self.unit.split(&characters);
+        let mut units = &*self.unit.split(&input);
+        if self.leading {
+            while let Some((first, rest)) = units.split_first() {
+                if !characters.contains(first) {
+                    break;
+                }
+                units = rest;
+            }
+        }
+        if self.trailing {
+            while let Some((last, rest)) = units.split_last() {
+                if !characters.contains(last) {
+                    break;
+                }
+                units = rest;
+            }
+        }
+        Ok(Compiled(C::Constant(units.concat().into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `replace` SQL function.
+#[derive(Debug)]
+pub struct Replace;
+
+impl Function for Replace {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (input, from, to) = args_3::<Vec<u8>, Vec<u8>, Vec<u8>>("replace", args, None, None, None)?;
+        if from.is_empty() {
+            return Ok(Compiled(C::Constant(input.into())));
+        }
+        let mut output = Vec::with_capacity(input.len());
+        let mut rest = &*input;
+        while let Some(pos) = find_bytes(rest, &from) {
+            output.extend_from_slice(&rest[..pos]);
+            output.extend_from_slice(&to);
+            rest = &rest[(pos + from.len())..];
+        }
+        output.extend_from_slice(rest);
+        Ok(Compiled(C::Constant(output.into())))
+    }
+}
+
+/// Finds the byte index of the first occurrence of `needle` in `haystack`.
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+//------------------------------------------------------------------------------
+
+/// The `lpad` and `rpad` SQL functions.
+#[derive(Debug)]
+pub struct Pad {
+    /// The string unit used by the function.
+    pub unit: Unit,
+    /// Whether to fill the start (true) or the end (false) of the string.
+    pub left: bool,
+}
+
+impl Function for Pad {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = if self.left { "lpad" } else { "rpad" };
+        let (input, length, fill) = args_3::<Value, isize, Vec<u8>>(name, args, None, None, Some(b" ".to_vec()))?;
+        // numbers are padded as strings, e.g. `lpad(rownum, 10, '0')` produces zero-padded numbers.
+        let input = match input {
+            Value::Number(_) => Value::sql_concat(once(input))?,
+            input => input,
+        };
+        let mut input = Vec::<u8>::try_from(input).map_err(|_| Error::InvalidArgumentType {
+            name,
+            index: 0,
+            expected: "string or number".to_owned(),
+        })?;
+        let length = length.try_into().unwrap_or(0_usize);
+        require(name, length <= MAX_STRING_LENGTH, || {
+            format!("length ({length}) must not exceed {MAX_STRING_LENGTH}")
+        })?;
+        let input_length = self.unit.length_of(&input);
+        if input_length >= length || fill.is_empty() {
+            input.truncate(self.unit.byte_index(&input, length));
+            return Ok(Compiled(C::Constant(input.into())));
+        }
+
+        let padding = self
+            .unit
+            .split(&fill)
+            .into_iter()
+            .cycle()
+            .take(length - input_length)
+            .flatten()
+            .copied();
+        if self.left {
+            input.splice(..0, padding);
+        } else {
+            input.extend(padding);
+        }
+        Ok(Compiled(C::Constant(input.into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `repeat` SQL function.
+#[derive(Debug)]
+pub struct Repeat;
+
+impl Function for Repeat {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "repeat";
+        let (input, count) = args_2::<Vec<u8>, isize>(name, args, None, None)?;
+        let count = count.try_into().unwrap_or(0_usize);
+        require(
+            name,
+            matches!(input.len().checked_mul(count), Some(len) if len <= MAX_STRING_LENGTH),
+            || {
+                format!(
+                    "repeating {} bytes {} times exceeds the maximum length of {} bytes",
+                    input.len(),
+                    count,
+                    MAX_STRING_LENGTH
+                )
+            },
+        )?;
+        Ok(Compiled(C::Constant(input.repeat(count).into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `position` (`position(needle IN haystack)`) and `strpos` (`strpos(haystack, needle)`) SQL
+/// functions.
+#[derive(Debug)]
+pub struct Position {
+    /// The string unit used by the function.
+    pub unit: Unit,
+    /// Whether the haystack comes before the needle in the arguments (i.e. `strpos`).
+    pub haystack_first: bool,
+}
+
+impl Function for Position {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = if self.haystack_first { "strpos" } else { "position" };
+        let (mut needle, mut haystack) = args_2::<Vec<u8>, Vec<u8>>(name, args, None, None)?;
+        if self.haystack_first {
+            std::mem::swap(&mut needle, &mut haystack);
+        }
+        let position = find_bytes(&haystack, &needle).map_or(0, |i| self.unit.length_of(&haystack[..i]) + 1);
+        Ok(Compiled(C::Constant(position.into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `left` and `right` SQL functions.
+#[derive(Debug)]
+pub struct LeftRight {
+    /// The string unit used by the function.
+    pub unit: Unit,
+    /// Whether to keep the start (true) or the end (false) of the string.
+    pub left: bool,
+}
+
+impl Function for LeftRight {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = if self.left { "left" } else { "right" };
+        let (mut input, count) = args_2::<Vec<u8>, isize>(name, args, None, None)?;
+
+        // a negative count means all but the last (or first) |count| units.
+        let length = self.unit.length_of(&input);
+        let count = if count < 0 {
+            length.saturating_sub(count.unsigned_abs())
+        } else {
+            count.unsigned_abs().min(length)
+        };
+        if self.left {
+            input.truncate(self.unit.byte_index(&input, count));
+        } else {
+            input.drain(..self.unit.byte_index(&input, length - count));
+        }
+        Ok(Compiled(C::Constant(input.into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `reverse` SQL function.
+#[derive(Debug)]
+pub struct Reverse(
+    /// The string unit used by the function.
+    pub Unit,
+);
+
+impl Function for Reverse {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let input = args_1::<Vec<u8>>("reverse", args, None)?;
+        let mut units = self.0.split(&input);
+        units.reverse();
+        Ok(Compiled(C::Constant(units.concat().into())))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `split_part` SQL function.
+#[derive(Debug)]
+pub struct SplitPart;
+
+impl Function for SplitPart {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "split_part";
+        let (input, delimiter, index) = args_3::<Vec<u8>, Vec<u8>, isize>(name, args, None, None, None)?;
+        require(name, index != 0, || "field position must not be zero".to_owned())?;
+
+        let mut parts = Vec::new();
+        let mut rest = &*input;
+        if delimiter.is_empty() {
+            parts.push(rest);
+        } else {
+            while let Some(pos) = find_bytes(rest, &delimiter) {
+                parts.push(&rest[..pos]);
+                rest = &rest[(pos + delimiter.len())..];
+            }
+            parts.push(rest);
+        }
+
+        // a negative index counts from the end.
+        let part = if index > 0 {
+            parts.get(index.unsigned_abs() - 1)
+        } else {
+            parts.len().checked_sub(index.unsigned_abs()).and_then(|i| parts.get(i))
+        };
+        Ok(Compiled(C::Constant(part.map_or(Vec::new(), |p| p.to_vec()).into())))
+    }
+}
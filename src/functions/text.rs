@@ -0,0 +1,196 @@
+//! Natural-language text generator functions.
+
+use super::{args_2, args_3, load::read_file, require, Arguments, Function};
+use crate::{
+    error::Error,
+    eval::{CompileContext, Compiled, C},
+};
+use rand::{distributions::Uniform, seq::SliceRandom, Rng};
+use std::{collections::HashMap, convert::TryFrom, sync::Arc};
+use zipf::ZipfDistribution;
+
+/// Words of the lorem ipsum vocabulary, roughly from the most frequent to the least.
+#[rustfmt::skip]
+static LOREM_WORDS: &[&str] = &[
+    "et", "in", "ut", "est", "non", "sed", "ad", "quis", "dolor", "ipsum",
+    "amet", "sit", "enim", "nisi", "esse", "velit", "aute", "irure", "eu", "ex",
+    "lorem", "dolore", "magna", "aliqua", "minim", "veniam", "nostrud", "ullamco", "laboris", "aliquip",
+    "commodo", "consequat", "duis", "reprehenderit", "voluptate", "cillum", "fugiat", "nulla", "pariatur", "excepteur",
+    "sint", "occaecat", "cupidatat", "proident", "sunt", "culpa", "qui", "officia", "deserunt", "mollit",
+    "anim", "id", "laborum", "consectetur", "adipiscing", "elit", "do", "eiusmod", "tempor", "incididunt",
+    "labore", "exercitation", "perspiciatis", "unde", "omnis", "iste", "natus", "error", "voluptatem", "accusantium",
+    "doloremque", "laudantium", "totam", "rem", "aperiam", "eaque", "ipsa", "quae", "ab", "illo",
+    "inventore", "veritatis", "quasi", "architecto", "beatae", "vitae", "dicta", "explicabo", "nemo", "ipsam",
+];
+
+/// The `rand.lorem` SQL function.
+#[derive(Debug)]
+pub struct Lorem;
+
+impl Function for Lorem {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.lorem";
+        let (min_words, max_words) = args_2::<usize, usize>(name, args, None, None)?;
+        require(name, 0 < min_words && min_words <= max_words, || {
+            format!("must have 0 < min_words ({min_words}) <= max_words ({max_words})")
+        })?;
+        Ok(Compiled(C::RandLorem(LoremGenerator {
+            words_count: Uniform::new_inclusive(min_words, max_words),
+            word: ZipfDistribution::new(LOREM_WORDS.len(), 1.0).unwrap(),
+        })))
+    }
+}
+
+/// Generates lorem ipsum text, where the word frequencies follow Zipf's law.
+#[derive(Clone, Debug)]
+pub struct LoremGenerator {
+    /// Distribution of the total number of words.
+    words_count: Uniform<usize>,
+    /// Distribution of the (1-based) word index.
+    word: ZipfDistribution,
+}
+
+impl LoremGenerator {
+    /// Generates a paragraph.
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> String {
+        let mut words_left = rng.sample(self.words_count);
+        let mut result = String::with_capacity(words_left * 7);
+        while words_left > 0 {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+            let sentence_len = rng.gen_range(4, 16).min(words_left);
+            words_left -= sentence_len;
+            for i in 0..sentence_len {
+                let word = LOREM_WORDS[rng.sample(self.word) - 1];
+                if i == 0 {
+                    let mut chars = word.chars();
+                    result.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+                    result.push_str(chars.as_str());
+                } else {
+                    result.push(' ');
+                    result.push_str(word);
+                }
+                if i + 1 == sentence_len {
+                    result.push('.');
+                } else if i > 1 && i + 2 < sentence_len && rng.gen_bool(0.1) {
+                    result.push(',');
+                }
+            }
+        }
+        result
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// The `rand.markov` SQL function.
+#[derive(Debug)]
+pub struct Markov;
+
+impl Function for Markov {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "rand.markov";
+        let (path, order, max_len) = args_3::<String, usize, usize>(name, args, None, None, None)?;
+        require(name, (1..=4).contains(&order), || {
+            format!("order ({order}) must be between 1 and 4")
+        })?;
+        let corpus = read_file(name, ctx, &path)?;
+        let chain = MarkovChain::new(&corpus, order).ok_or_else(|| Error::InvalidArguments {
+            name,
+            cause: format!("corpus '{path}' must contain more than {order} words"),
+        })?;
+        Ok(Compiled(C::RandMarkov {
+            chain: Arc::new(chain),
+            max_len,
+        }))
+    }
+}
+
+/// A word-level Markov chain model.
+#[derive(Debug)]
+pub struct MarkovChain {
+    /// All distinct words in the corpus.
+    words: Vec<Box<str>>,
+    /// Maps each state (the last *order* word indices) to all words following it in the corpus.
+    /// A word following the state multiple times is repeated, so that a uniform choice reproduces
+    /// the frequencies of the corpus.
+    transitions: HashMap<Box<[u32]>, Box<[u32]>>,
+    /// States which start a sentence.
+    starts: Vec<Box<[u32]>>,
+}
+
+/// Checks whether the word ends a sentence.
+fn is_sentence_end(word: &str) -> bool {
+    word.ends_with(['.', '!', '?'])
+}
+
+impl MarkovChain {
+    /// Builds the Markov chain from the whitespace-separated words of the corpus. Returns `None` if
+    /// the corpus is too short.
+    fn new(corpus: &str, order: usize) -> Option<Self> {
+        let mut indices = HashMap::new();
+        let mut words = Vec::new();
+        let tokens = corpus
+            .split_whitespace()
+            .map(|word| {
+                *indices.entry(word).or_insert_with(|| {
+                    words.push(Box::from(word));
+                    u32::try_from(words.len() - 1).unwrap()
+                })
+            })
+            .collect::<Vec<_>>();
+        if tokens.len() <= order {
+            return None;
+        }
+
+        let mut transitions = HashMap::<_, Vec<_>>::new();
+        let mut starts = Vec::new();
+        for (i, window) in tokens.windows(order + 1).enumerate() {
+            let state = Box::<[u32]>::from(&window[..order]);
+            if i == 0 || is_sentence_end(&words[tokens[i - 1] as usize]) {
+                starts.push(state.clone());
+            }
+            transitions.entry(state).or_default().push(window[order]);
+        }
+        Some(Self {
+            words,
+            transitions: transitions.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            starts,
+        })
+    }
+
+    /// Generates text by walking the chain, stopping before the length exceeds `max_len`
+    /// characters. When the walk reaches a dead end, it restarts from a random sentence start.
+    pub(crate) fn sample<R: Rng + ?Sized>(&self, rng: &mut R, max_len: usize) -> String {
+        let mut result = String::new();
+        let mut len = 0;
+        let mut state = Vec::<u32>::new();
+        loop {
+            let next_words = if let Some(next) = self.transitions.get(&*state) {
+                vec![*next.choose(rng).unwrap()]
+            } else {
+                state.clear();
+                self.starts.choose(rng).unwrap().to_vec()
+            };
+            for word_index in next_words {
+                let word = &self.words[word_index as usize];
+                let word_len = word.chars().count();
+                let sep_len = usize::from(len > 0);
+                if len + sep_len + word_len > max_len {
+                    return result;
+                }
+                if len > 0 {
+                    result.push(' ');
+                }
+                result.push_str(word);
+                len += sep_len + word_len;
+                state.push(word_index);
+            }
+            let order = self.starts[0].len();
+            if state.len() > order {
+                state.drain(..state.len() - order);
+            }
+        }
+    }
+}
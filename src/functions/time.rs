@@ -1,14 +1,18 @@
 //! Time functions.
 
-use super::{args_1, Arguments, Function};
+use super::{args_1, args_2, Arguments, Function};
 use crate::{
     error::Error,
     eval::{CompileContext, Compiled, C},
-    value::{Value, TIMESTAMP_FORMAT},
+    value::{from_local, DateOrTimestamp, Number, Value, DATE_FORMAT, TIMESTAMP_FORMAT, TIME_FORMAT},
 };
 
-use chrono::TimeZone;
+use chrono::{
+    format::{Item, StrftimeItems},
+    Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+};
 use chrono_tz::Tz;
+use std::convert::TryFrom;
 
 /// The `timestamp` SQL function
 #[derive(Debug)]
@@ -57,3 +61,322 @@ impl Function for TimestampWithTimeZone {
         Ok(Compiled(C::Constant(Value::Timestamp(timestamp, tz))))
     }
 }
+
+/// The `date` SQL function
+#[derive(Debug)]
+pub struct Date;
+
+impl Function for Date {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "date";
+        let date = match args_1::<Value>(name, args, None)? {
+            Value::Date(date) => date,
+            Value::Timestamp(ts, tz) => to_local(ts, tz).date(),
+            input => {
+                let input = String::try_from(input).map_err(|_| Error::InvalidArgumentType {
+                    name,
+                    index: 0,
+                    expected: "string or timestamp".to_owned(),
+                })?;
+                NaiveDate::parse_from_str(&input, DATE_FORMAT).map_err(|source| Error::InvalidTimestampString {
+                    timestamp: input,
+                    source,
+                })?
+            }
+        };
+        Ok(Compiled(C::Constant(Value::Date(date))))
+    }
+}
+
+/// The `time` SQL function
+#[derive(Debug)]
+pub struct Time;
+
+impl Function for Time {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "time";
+        let time = match args_1::<Value>(name, args, None)? {
+            Value::Time(time) => time,
+            Value::Timestamp(ts, tz) => to_local(ts, tz).time(),
+            input => {
+                let input = String::try_from(input).map_err(|_| Error::InvalidArgumentType {
+                    name,
+                    index: 0,
+                    expected: "string or timestamp".to_owned(),
+                })?;
+                NaiveTime::parse_from_str(&input, TIME_FORMAT).map_err(|source| Error::InvalidTimestampString {
+                    timestamp: input,
+                    source,
+                })?
+            }
+        };
+        Ok(Compiled(C::Constant(Value::Time(time))))
+    }
+}
+
+//------------------------------------------------------------------------------
+
+/// Converts a UTC timestamp into the local time of the time zone.
+fn to_local(ts: NaiveDateTime, tz: Tz) -> NaiveDateTime {
+    tz.from_utc_datetime(&ts).naive_local()
+}
+
+/// The UNIX epoch.
+fn unix_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1970, 1, 1)
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .expect("valid date")
+}
+
+/// Adds the number of months to the date, clamping the day to the end of the month.
+fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    let total = (date.year() * 12 + i32::try_from(date.month0()).ok()?).checked_add(months)?;
+    let year = total.div_euclid(12);
+    let month = total.rem_euclid(12) as u32 + 1;
+    let day = date.day();
+    (day.min(28)..=day)
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+}
+
+//------------------------------------------------------------------------------
+
+/// The `extract` SQL function.
+#[derive(Debug)]
+pub struct Extract;
+
+impl Function for Extract {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "extract";
+        let (field, input) = args_2::<String, DateOrTimestamp>(name, args, None, None)?;
+        let (ts, tz) = input.to_timestamp(ctx.time_zone)?;
+        let local = to_local(ts, tz);
+        let result: Number = match &*field.to_ascii_lowercase() {
+            "year" => local.year().into(),
+            "quarter" => (local.month0() / 3 + 1).into(),
+            "month" => local.month().into(),
+            "week" => local.iso_week().week().into(),
+            "day" => local.day().into(),
+            "hour" => local.hour().into(),
+            "minute" => local.minute().into(),
+            "second" => local.second().into(),
+            "microsecond" => (local.nanosecond() / 1000).into(),
+            "dow" => local.weekday().num_days_from_sunday().into(),
+            "isodow" => local.weekday().number_from_monday().into(),
+            "doy" => local.ordinal().into(),
+            "epoch" => epoch_of(ts),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    name,
+                    cause: format!("unknown field '{field}'"),
+                })
+            }
+        };
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+/// Computes the number of seconds since the UNIX epoch.
+fn epoch_of(ts: NaiveDateTime) -> Number {
+    let duration = ts.signed_duration_since(unix_epoch());
+    let seconds = duration.num_seconds();
+    let micros = (duration - Duration::seconds(seconds)).num_microseconds().unwrap_or(0);
+    if micros == 0 {
+        seconds.into()
+    } else {
+        #[allow(clippy::cast_precision_loss)]
+        (seconds as f64 + micros as f64 / 1e6).into()
+    }
+}
+
+/// The `date_trunc` SQL function.
+#[derive(Debug)]
+pub struct DateTrunc;
+
+impl Function for DateTrunc {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "date_trunc";
+        let (unit, input) = args_2::<String, DateOrTimestamp>(name, args, None, None)?;
+        let (ts, tz) = input.to_timestamp(ctx.time_zone)?;
+        let local = to_local(ts, tz);
+        let date = local.date();
+        let time = local.time();
+        let (date, time) = match &*unit.to_ascii_lowercase() {
+            "year" => (date.with_ordinal(1), NaiveTime::MIN),
+            "quarter" => (
+                date.with_day(1).and_then(|d| d.with_month0(date.month0() / 3 * 3)),
+                NaiveTime::MIN,
+            ),
+            "month" => (date.with_day(1), NaiveTime::MIN),
+            "week" => (
+                Some(date - Duration::days(date.weekday().num_days_from_monday().into())),
+                NaiveTime::MIN,
+            ),
+            "day" => (Some(date), NaiveTime::MIN),
+            "hour" => (
+                Some(date),
+                time.with_minute(0).and_then(|t| t.with_second(0)).unwrap_or(time),
+            ),
+            "minute" => (Some(date), time.with_second(0).unwrap_or(time)),
+            "second" => (Some(date), time),
+            _ => {
+                return Err(Error::InvalidArguments {
+                    name,
+                    cause: format!("unknown unit '{unit}'"),
+                })
+            }
+        };
+        let date = date.expect("valid date");
+        if let DateOrTimestamp::Date(_) = input {
+            return Ok(Compiled(C::Constant(Value::Date(date))));
+        }
+        let local = date.and_time(time.with_nanosecond(0).unwrap_or(time));
+        let ts =
+            from_local(local, tz).ok_or_else(|| Error::IntegerOverflow(format!("date_trunc('{unit}', {local})")))?;
+        Ok(Compiled(C::Constant(Value::Timestamp(ts, tz))))
+    }
+}
+
+/// The calendar-aware `add_months` and `add_years` SQL functions.
+#[derive(Debug)]
+pub struct AddMonths {
+    /// Number of months per unit of the argument (1 for `add_months`, 12 for `add_years`).
+    pub months_per_unit: i32,
+}
+
+impl Function for AddMonths {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = if self.months_per_unit == 1 {
+            "add_months"
+        } else {
+            "add_years"
+        };
+        let (input, n) = args_2::<DateOrTimestamp, i32>(name, args, None, None)?;
+        let months = n.checked_mul(self.months_per_unit);
+        let result = match input {
+            DateOrTimestamp::Date(date) => Value::Date(
+                months
+                    .and_then(|months| add_months(date, months))
+                    .ok_or_else(|| Error::IntegerOverflow(format!("{name}({date}, {n})")))?,
+            ),
+            DateOrTimestamp::Timestamp(ts, tz) => {
+                let local = to_local(ts, tz);
+                let result = months
+                    .and_then(|months| add_months(local.date(), months))
+                    .and_then(|date| from_local(date.and_time(local.time()), tz))
+                    .ok_or_else(|| Error::IntegerOverflow(format!("{name}({local}, {n})")))?;
+                Value::Timestamp(result, tz)
+            }
+        };
+        Ok(Compiled(C::Constant(result)))
+    }
+}
+
+/// The `last_day` SQL function.
+#[derive(Debug)]
+pub struct LastDay;
+
+impl Function for LastDay {
+    fn compile(&self, _: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let input = args_1::<DateOrTimestamp>("last_day", args, None)?;
+        let (local, tz) = match input {
+            DateOrTimestamp::Date(date) => (date.and_time(NaiveTime::MIN), None),
+            DateOrTimestamp::Timestamp(ts, tz) => (to_local(ts, tz), Some(tz)),
+        };
+        let last_day = local
+            .date()
+            .with_day(1)
+            .and_then(|d| add_months(d, 1))
+            .and_then(|d| d.pred_opt());
+        let result = match tz {
+            None => last_day.map(Value::Date),
+            Some(tz) => last_day
+                .and_then(|d| from_local(d.and_time(NaiveTime::MIN), tz))
+                .map(|ts| Value::Timestamp(ts, tz)),
+        };
+        let result = result.ok_or_else(|| Error::IntegerOverflow(format!("last_day({local})")))?;
+        Ok(Compiled(C::Constant(result)))
+    }
+}
+
+/// The `day_of_week` SQL function.
+#[derive(Debug)]
+pub struct DayOfWeek;
+
+impl Function for DayOfWeek {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (ts, tz) = args_1::<DateOrTimestamp>("day_of_week", args, None)?.to_timestamp(ctx.time_zone)?;
+        let result = to_local(ts, tz).weekday().number_from_monday();
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+/// The `epoch` SQL function.
+#[derive(Debug)]
+pub struct Epoch;
+
+impl Function for Epoch {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let (ts, _) = args_1::<DateOrTimestamp>("epoch", args, None)?.to_timestamp(ctx.time_zone)?;
+        Ok(Compiled(C::Constant(epoch_of(ts).into())))
+    }
+}
+
+/// The `to_timestamp` SQL function.
+#[derive(Debug)]
+pub struct ToTimestamp;
+
+impl Function for ToTimestamp {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let seconds = args_1::<f64>("to_timestamp", args, None)?;
+        let micros = (seconds * 1e6).round();
+        let ts = if micros.abs() < 9.2e18 {
+            #[allow(clippy::cast_possible_truncation)]
+            unix_epoch().checked_add_signed(Duration::microseconds(micros as i64))
+        } else {
+            None
+        };
+        let ts = ts.ok_or_else(|| Error::IntegerOverflow(format!("to_timestamp({seconds})")))?;
+        Ok(Compiled(C::Constant(Value::Timestamp(ts, ctx.time_zone))))
+    }
+}
+
+/// The `format_timestamp` SQL function.
+#[derive(Debug)]
+pub struct FormatTimestamp;
+
+impl Function for FormatTimestamp {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "format_timestamp";
+        let (input, format) = args_2::<DateOrTimestamp, String>(name, args, None, None)?;
+        let (ts, tz) = input.to_timestamp(ctx.time_zone)?;
+        let items = StrftimeItems::new(&format).collect::<Vec<_>>();
+        if items.iter().any(|item| matches!(item, Item::Error)) {
+            return Err(Error::InvalidArguments {
+                name,
+                cause: format!("invalid format string '{format}'"),
+            });
+        }
+        let result = tz
+            .from_utc_datetime(&ts)
+            .format_with_items(items.into_iter())
+            .to_string();
+        Ok(Compiled(C::Constant(result.into())))
+    }
+}
+
+/// The `AT TIME ZONE` SQL operator.
+#[derive(Debug)]
+pub struct AtTimeZone;
+
+impl Function for AtTimeZone {
+    fn compile(&self, ctx: &CompileContext, args: Arguments) -> Result<Compiled, Error> {
+        let name = "at time zone";
+        let (input, tz) = args_2::<DateOrTimestamp, String>(name, args, None, None)?;
+        let (ts, _) = input.to_timestamp(ctx.time_zone)?;
+        let tz = tz
+            .parse::<Tz>()
+            .map_err(|cause| Error::InvalidArguments { name, cause })?;
+        Ok(Compiled(C::Constant(Value::Timestamp(ts, tz))))
+    }
+}
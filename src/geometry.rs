@@ -0,0 +1,145 @@
+//! Geometry values
+
+use std::{fmt, io::Write};
+
+/// The SRID of the WGS 84 geographic coordinate system, used by geography values.
+pub const WGS84_SRID: u32 = 4326;
+
+/// A 2-dimensional coordinate.
+pub type Coord = (f64, f64);
+
+/// The shape of a geometry.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    /// A single point.
+    Point(Coord),
+    /// A sequence of points connected by straight lines.
+    LineString(Box<[Coord]>),
+    /// A polygon, consisting of the exterior ring followed by the interior rings (holes). Every ring
+    /// must be closed, i.e. the first and last coordinates are the same.
+    Polygon(Box<[Box<[Coord]>]>),
+}
+
+/// A geometry value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Geometry {
+    /// The shape of the geometry.
+    pub shape: Shape,
+    /// Whether the coordinates are (longitude, latitude) pairs in the WGS 84 geographic coordinate
+    /// system, instead of points on a plane.
+    pub is_geography: bool,
+}
+
+impl From<Shape> for Geometry {
+    fn from(shape: Shape) -> Self {
+        Self {
+            shape,
+            is_geography: false,
+        }
+    }
+}
+
+/// Writes a list of coordinates in WKT format, e.g. `1 2,3 4`.
+fn write_wkt_coords(f: &mut fmt::Formatter<'_>, coords: &[Coord]) -> fmt::Result {
+    for (i, (x, y)) in coords.iter().enumerate() {
+        if i != 0 {
+            f.write_str(",")?;
+        }
+        write!(f, "{x} {y}")?;
+    }
+    Ok(())
+}
+
+/// Displays the geometry in Well-known text (WKT) format.
+impl fmt::Display for Geometry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.shape {
+            Shape::Point((x, y)) => write!(f, "POINT({x} {y})"),
+            Shape::LineString(coords) => {
+                f.write_str("LINESTRING(")?;
+                write_wkt_coords(f, coords)?;
+                f.write_str(")")
+            }
+            Shape::Polygon(rings) => {
+                f.write_str("POLYGON(")?;
+                for (i, ring) in rings.iter().enumerate() {
+                    f.write_str(if i == 0 { "(" } else { ",(" })?;
+                    write_wkt_coords(f, ring)?;
+                    f.write_str(")")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// Writes a list of coordinates in little-endian WKB format, prefixed by its length.
+fn write_wkb_coords(res: &mut Vec<u8>, coords: &[Coord]) {
+    #[allow(clippy::cast_possible_truncation)]
+    res.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+    for (x, y) in coords {
+        res.extend_from_slice(&x.to_le_bytes());
+        res.extend_from_slice(&y.to_le_bytes());
+    }
+}
+
+impl Geometry {
+    /// Encodes the geometry in little-endian Well-known binary (WKB) format.
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut res = vec![1];
+        match &self.shape {
+            Shape::Point((x, y)) => {
+                res.extend_from_slice(&1_u32.to_le_bytes());
+                res.extend_from_slice(&x.to_le_bytes());
+                res.extend_from_slice(&y.to_le_bytes());
+            }
+            Shape::LineString(coords) => {
+                res.extend_from_slice(&2_u32.to_le_bytes());
+                write_wkb_coords(&mut res, coords);
+            }
+            Shape::Polygon(rings) => {
+                res.extend_from_slice(&3_u32.to_le_bytes());
+                #[allow(clippy::cast_possible_truncation)]
+                res.extend_from_slice(&(rings.len() as u32).to_le_bytes());
+                for ring in &**rings {
+                    write_wkb_coords(&mut res, ring);
+                }
+            }
+        }
+        res
+    }
+
+    /// Writes the geometry as an SQL expression `ST_GeomFromText('…')`. Geography values also
+    /// include the WGS 84 SRID, with coordinates in the longitude-latitude order used by `PostGIS`.
+    /// If `mysql_axis_order` is set, geography values also include the `axis-order=long-lat` option
+    /// so `MySQL` reads the coordinates in the same order.
+    pub(crate) fn write_sql(&self, writer: &mut dyn Write, mysql_axis_order: bool) -> std::io::Result<()> {
+        write!(writer, "ST_GeomFromText('{self}'")?;
+        if self.is_geography {
+            write!(writer, ", {WGS84_SRID}")?;
+            if mysql_axis_order {
+                writer.write_all(b", 'axis-order=long-lat'")?;
+            }
+        }
+        writer.write_all(b")")
+    }
+}
+
+#[test]
+fn test_wkt_and_wkb() {
+    let point = Geometry::from(Shape::Point((1.5, -2.0)));
+    assert_eq!(point.to_string(), "POINT(1.5 -2)");
+    assert_eq!(
+        point.to_wkb(),
+        b"\x01\x01\0\0\0\0\0\0\0\0\0\xf8\x3f\0\0\0\0\0\0\0\xc0".to_vec()
+    );
+
+    let polygon = Geometry::from(Shape::Polygon(
+        vec![vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (0.0, 0.0)].into()].into(),
+    ));
+    assert_eq!(polygon.to_string(), "POLYGON((0 0,1 0,0 1,0 0))");
+    assert_eq!(polygon.to_wkb().len(), 1 + 4 + 4 + 4 + 16 * 4);
+
+    let line = Geometry::from(Shape::LineString(vec![(0.0, 0.0), (3.0, 4.0)].into()));
+    assert_eq!(line.to_string(), "LINESTRING(0 0,3 4)");
+}
@@ -23,10 +23,12 @@ pub const FULL_VERSION: &str = concat!(
 );
 
 pub mod cli;
+pub mod decimal;
 pub mod error;
 pub mod eval;
 pub mod format;
 pub mod functions;
+pub mod geometry;
 pub mod parser;
 pub mod schemagen_cli;
 pub mod value;
@@ -389,9 +389,12 @@ impl<'a> Allocator<'a> {
         for pair in pairs {
             let rule = pair.as_rule();
             match rule {
-                Rule::expr_bit_or | Rule::expr_bit_and | Rule::expr_and | Rule::expr_add | Rule::expr_mul => {
-                    args.push(self.expr_binary_from_pairs(pair.into_inner())?)
-                }
+                Rule::expr_bit_or
+                | Rule::expr_bit_and
+                | Rule::expr_shift
+                | Rule::expr_and
+                | Rule::expr_add
+                | Rule::expr_mul => args.push(self.expr_binary_from_pairs(pair.into_inner())?),
                 Rule::expr_not => args.push(self.expr_not_from_pairs(pair.into_inner())?),
                 Rule::expr_unary => args.push(self.expr_unary_from_pairs(pair.into_inner())?),
                 Rule::expr => args.push(self.expr_from_pairs(pair.into_inner())?),
@@ -413,6 +416,8 @@ impl<'a> Allocator<'a> {
                 | Rule::op_bit_and
                 | Rule::op_bit_or
                 | Rule::op_bit_xor
+                | Rule::op_shl
+                | Rule::op_shr
                 | Rule::op_semicolon => {
                     match op {
                         Some(o) if o != rule => {
@@ -478,12 +483,17 @@ impl<'a> Allocator<'a> {
             Rule::expr_group => self.expr_group_from_pairs(pair.into_inner())?,
             Rule::number => Expr::Value(parse_number(pair.as_str())?),
             Rule::expr_timestamp => self.expr_timestamp_from_pairs(pair.into_inner())?,
+            Rule::expr_date => self.expr_literal_from_pairs(&functions::time::Date, pair.into_inner())?,
+            Rule::expr_time => self.expr_literal_from_pairs(&functions::time::Time, pair.into_inner())?,
             Rule::expr_interval => self.expr_interval_from_pairs(pair.into_inner())?,
             Rule::expr_get_variable => self.expr_get_variable_from_pairs(pair.into_inner())?,
             Rule::expr_array => self.expr_array_from_pairs(pair.into_inner())?,
             Rule::expr_function => self.expr_function_from_pairs(pair.into_inner())?,
             Rule::expr_substring_function => self.expr_substring_from_pairs(pair.into_inner())?,
             Rule::expr_overlay_function => self.expr_overlay_from_pairs(pair.into_inner())?,
+            Rule::expr_position_function => self.expr_position_from_pairs(pair.into_inner())?,
+            Rule::expr_trim_function => self.expr_trim_from_pairs(pair.into_inner())?,
+            Rule::expr_extract_function => self.expr_extract_from_pairs(pair.into_inner())?,
             Rule::expr_case_value_when => self.expr_case_value_when_from_pairs(pair.into_inner())?,
 
             Rule::single_quoted => {
@@ -498,20 +508,34 @@ impl<'a> Allocator<'a> {
 
     /// Creates a function call expression `x.y.z(a, b, c)`.
     fn expr_function_from_pairs(&mut self, pairs: Pairs<'_, Rule>) -> Result<Expr, Error> {
-        let mut function: &dyn Function = &functions::ops::Last;
+        use functions::string::Unit;
+
+        let mut q_name = QName::default();
+        let mut unit = None;
         let mut args = Vec::new();
 
         for pair in pairs {
             match pair.as_rule() {
-                Rule::qname => {
-                    let q_name = QName::from_pairs(pair.into_inner(), [None; 2]);
-                    function = function_from_name(q_name.unique_name())?;
-                }
+                Rule::qname => q_name = QName::from_pairs(pair.into_inner(), [None; 2]),
                 Rule::expr => args.push(self.expr_from_pairs(pair.into_inner())?),
+                Rule::kw_using => {}
+                Rule::kw_octets => unit = Some(Unit::Octets),
+                Rule::kw_characters => unit = Some(Unit::Characters),
                 r => unreachable!("Unexpected rule {:?}", r),
             }
         }
 
+        let name = q_name.unique_name();
+        let function = if let Some(unit) = unit {
+            let unit_name = match unit {
+                Unit::Characters => "characters",
+                Unit::Octets => "octets",
+            };
+            unit_function_from_name(name, unit)
+                .ok_or_else(|| Error::UnknownFunction(format!("{name} using {unit_name}")))?
+        } else {
+            function_from_name(name)?
+        };
         Ok(Expr::Function { function, args })
     }
 
@@ -562,6 +586,13 @@ impl<'a> Allocator<'a> {
                         args: vec![base, self.expr_from_pairs(pair.into_inner())?],
                     }
                 }
+                Rule::at_time_zone => {
+                    let time_zone = pair.into_inner().find(|p| p.as_rule() == Rule::expr_primary).unwrap();
+                    base = Expr::Function {
+                        function: &functions::time::AtTimeZone,
+                        args: vec![base, self.expr_primary_from_pairs(time_zone.into_inner())?],
+                    }
+                }
                 r => unreachable!("Unexpected rule {:?}", r),
             }
         }
@@ -635,6 +666,46 @@ impl<'a> Allocator<'a> {
         unreachable!("Pairs exhausted without finding the inner expression");
     }
 
+    /// Creates a `DATE` or `TIME` expression.
+    fn expr_literal_from_pairs(
+        &mut self,
+        function: &'static dyn Function,
+        pairs: Pairs<'_, Rule>,
+    ) -> Result<Expr, Error> {
+        for pair in pairs {
+            match pair.as_rule() {
+                Rule::kw_date | Rule::kw_time => {}
+                Rule::expr_primary => {
+                    return Ok(Expr::Function {
+                        function,
+                        args: vec![self.expr_primary_from_pairs(pair.into_inner())?],
+                    });
+                }
+                r => unreachable!("Unexpected rule {:?}", r),
+            }
+        }
+
+        unreachable!("Pairs exhausted without finding the inner expression");
+    }
+
+    /// Creates an `extract(field FROM ts)` expression.
+    fn expr_extract_from_pairs(&mut self, pairs: Pairs<'_, Rule>) -> Result<Expr, Error> {
+        let mut args = Vec::with_capacity(2);
+        for pair in pairs {
+            match pair.as_rule() {
+                Rule::kw_extract | Rule::kw_from => {}
+                Rule::ident => args.push(Expr::Value(pair.as_str().to_owned().into())),
+                Rule::expr => args.push(self.expr_from_pairs(pair.into_inner())?),
+                r => unreachable!("Unexpected rule {:?}", r),
+            }
+        }
+
+        Ok(Expr::Function {
+            function: &functions::time::Extract,
+            args,
+        })
+    }
+
     /// Creates an `INTERVAL` expression.
     fn expr_interval_from_pairs(&mut self, pairs: Pairs<'_, Rule>) -> Result<Expr, Error> {
         let mut unit = 1;
@@ -732,6 +803,67 @@ impl<'a> Allocator<'a> {
         }
         Ok(Expr::Function { function, args })
     }
+
+    /// Creates a `position` function expression.
+    fn expr_position_from_pairs(&mut self, pairs: Pairs<'_, Rule>) -> Result<Expr, Error> {
+        use functions::string::{Position, Unit};
+
+        let mut function = &Position {
+            unit: Unit::Characters,
+            haystack_first: false,
+        };
+        let mut args = Vec::with_capacity(2);
+
+        for pair in pairs {
+            match pair.as_rule() {
+                Rule::kw_position | Rule::kw_in | Rule::kw_using | Rule::kw_characters => {}
+                Rule::kw_octets => {
+                    function = &Position {
+                        unit: Unit::Octets,
+                        haystack_first: false,
+                    }
+                }
+                Rule::substring_input | Rule::position_string => {
+                    args.push(self.expr_group_from_pairs(pair.into_inner())?);
+                }
+                r => unreachable!("Unexpected rule {:?}", r),
+            }
+        }
+
+        Ok(Expr::Function { function, args })
+    }
+
+    /// Creates a `trim` function expression.
+    fn expr_trim_from_pairs(&mut self, pairs: Pairs<'_, Rule>) -> Result<Expr, Error> {
+        use functions::string::Unit;
+
+        let mut name = "trim";
+        let mut unit = Unit::Characters;
+        let mut input = Expr::default();
+        let mut characters = None;
+
+        for pair in pairs {
+            match pair.as_rule() {
+                Rule::kw_trim | Rule::kw_both | Rule::kw_from | Rule::kw_using => {}
+                Rule::kw_leading => name = "ltrim",
+                Rule::kw_trailing => name = "rtrim",
+                Rule::kw_octets => unit = Unit::Octets,
+                Rule::kw_characters => unit = Unit::Characters,
+                Rule::substring_input => input = self.expr_group_from_pairs(pair.into_inner())?,
+                Rule::trim_characters => characters = Some(self.expr_group_from_pairs(pair.into_inner())?),
+                r => unreachable!("Unexpected rule {:?}", r),
+            }
+        }
+
+        let mut args = vec![input];
+        if let Some(characters) = characters {
+            args.push(characters);
+        }
+        Ok(Expr::Function {
+            function: unit_function_from_name(name, unit).expect("trim functions are unit-aware"),
+            args,
+        })
+    }
 }
 
 /// Parses a number (integer or floating-point number) into a value.
@@ -751,38 +883,279 @@ fn parse_number(input: &str) -> Result<Value, Error> {
 }
 
 /// Obtains a function from its name.
+#[allow(clippy::too_many_lines)]
 fn function_from_name(name: &str) -> Result<&'static dyn Function, Error> {
     use functions::{
-        array, ops, rand,
+        array, codec, fake, geo, json, load, ops, rand,
         string::{self, Unit},
+        text, time,
     };
 
     Ok(match name {
         "rand.regex" => &rand::Regex,
+        "rand.bytes" => &rand::Compressible {
+            name: "rand.bytes",
+            text: false,
+        },
+        "rand.text" => &rand::Compressible {
+            name: "rand.text",
+            text: true,
+        },
         "rand.range" => &rand::Range,
         "rand.range_inclusive" => &rand::RangeInclusive,
         "rand.uniform" => &rand::Uniform,
         "rand.uniform_inclusive" => &rand::UniformInclusive,
         "rand.zipf" => &rand::Zipf,
         "rand.log_normal" => &rand::LogNormal,
+        "rand.normal" => &rand::Normal,
+        "rand.exponential" => &rand::Exponential,
+        "rand.poisson" => &rand::Poisson,
+        "rand.binomial" => &rand::Binomial,
+        "rand.geometric" => &rand::Geometric,
+        "rand.gamma" => &rand::Gamma,
+        "rand.beta" => &rand::Beta,
+        "rand.weibull" => &rand::Weibull,
+        "rand.pareto" => &rand::Pareto,
+        "rand.triangular" => &rand::Triangular,
+        "rand.cauchy" => &rand::Cauchy,
         "rand.bool" => &rand::Bool,
         "rand.finite_f32" => &rand::FiniteF32,
         "rand.finite_f64" => &rand::FiniteF64,
         "rand.u31_timestamp" => &rand::U31Timestamp,
+        "rand.decimal" => &rand::Decimal,
         "rand.shuffle" => &rand::Shuffle,
+        "rand.choice" => &rand::Choice,
+        "rand.weighted" => &rand::Weighted,
+        "rand.sample" => &rand::Sample,
+        "rand.histogram" => &rand::Histogram,
+        "rand.quantiles" => &rand::Quantiles,
+        "rand.lorem" => &text::Lorem,
+        "rand.markov" => &text::Markov,
+        "rand.mvnormal" => &rand::MvNormal,
+        "rand.copula" => &rand::Copula,
+        "rand.permute" => &rand::Permute,
+        "rand.point" => &geo::RandPoint,
+        "rand.point_in" => &geo::RandPointIn,
+        "rand.linestring" => &geo::RandLineString,
+        "rand.polygon" => &geo::RandPolygon,
         "rand.uuid" => &rand::Uuid,
+        "rand.ipv4" => &rand::Ipv4,
+        "rand.ipv4_in" => &rand::Ipv4In,
+        "rand.ipv6" => &rand::Ipv6,
+        "rand.mac" => &rand::Mac,
+        "uuid_v7" => &rand::UuidV7,
+        "ulid" => &rand::Ulid,
+        "ksuid" => &rand::Ksuid,
+        "snowflake" => &rand::Snowflake,
         "greatest" => &ops::Extremum {
             order: Ordering::Greater,
         },
         "least" => &ops::Extremum { order: Ordering::Less },
         "round" => &ops::Round,
+        "decimal" => &ops::ToDecimal,
         "div" => &ops::Div,
         "mod" => &ops::Mod,
-        "char_length" | "character_length" => &string::Length(Unit::Characters),
+        "abs" => &ops::Abs,
+        "sign" => &ops::Sign,
+        "floor" => &ops::Integral::Floor,
+        "ceil" | "ceiling" => &ops::Integral::Ceil,
+        "trunc" => &ops::Integral::Trunc,
+        "power" | "pow" => &ops::Power,
+        "sqrt" => &ops::Float::Sqrt,
+        "exp" => &ops::Float::Exp,
+        "ln" => &ops::Float::Ln,
+        "log10" => &ops::Float::Log10,
+        "log" => &ops::Log,
+        "sin" => &ops::Float::Sin,
+        "cos" => &ops::Float::Cos,
+        "tan" => &ops::Float::Tan,
+        "asin" => &ops::Float::Asin,
+        "acos" => &ops::Float::Acos,
+        "atan" => &ops::Float::Atan,
+        "atan2" => &ops::Atan2,
+        "pi" => &ops::Pi,
         "octet_length" => &string::Length(Unit::Octets),
+        "upper" => &string::ChangeCase::Upper,
+        "lower" => &string::ChangeCase::Lower,
+        "initcap" => &string::ChangeCase::InitCap,
+        "replace" => &string::Replace,
+        "repeat" => &string::Repeat,
+        "split_part" => &string::SplitPart,
         "coalesce" => &ops::Coalesce,
+        "md5" => &codec::Digest::Md5,
+        "sha1" => &codec::Digest::Sha1,
+        "sha256" => &codec::Digest::Sha256,
+        "sha512" => &codec::Digest::Sha512,
+        "crc32" => &codec::Checksum::Crc32,
+        "fnv1a32" => &codec::Checksum::Fnv1a32,
+        "fnv1a64" => &codec::Checksum::Fnv1a64,
+        "xxh64" => &codec::Xxh64,
+        "to_hex" => &codec::ToHex,
+        "from_hex" => &codec::FromHex,
+        "to_base64" => &codec::ToBase64,
+        "from_base64" => &codec::FromBase64,
+        "url_encode" => &codec::UrlEncode,
+        "url_decode" => &codec::UrlDecode,
+        "convert_from" => &codec::ConvertFrom,
+        "quote_ident" => &codec::Quote { quote: b'"' },
+        "quote_literal" => &codec::Quote { quote: b'\'' },
+        "inet_aton" => &codec::InetAton,
+        "inet_ntoa" => &codec::InetNtoa,
+        "json_object" => &json::Object,
+        "json_array" => &json::Array,
+        "json_bool" => &json::Bool,
+        "json_merge" => &json::Merge,
+        "json_extract" => &json::Extract,
+        "st_point" => &geo::Point,
+        "st_astext" => &geo::AsText,
+        "st_asbinary" => &geo::AsBinary,
+        "geography" => &geo::Geography,
+        "load_lines" => &load::LoadLines,
+        "load_csv" => &load::LoadCsv,
+        "fake.first_name" => &fake::Fake {
+            name: "fake.first_name",
+            field: fake::Field::FirstName,
+        },
+        "fake.last_name" => &fake::Fake {
+            name: "fake.last_name",
+            field: fake::Field::LastName,
+        },
+        "fake.street_address" => &fake::Fake {
+            name: "fake.street_address",
+            field: fake::Field::StreetAddress,
+        },
+        "fake.city" => &fake::Fake {
+            name: "fake.city",
+            field: fake::Field::City,
+        },
+        "fake.company" => &fake::Fake {
+            name: "fake.company",
+            field: fake::Field::Company,
+        },
+        "fake.phone" => &fake::Fake {
+            name: "fake.phone",
+            field: fake::Field::Phone,
+        },
+        "fake.country_code" => &fake::CountryCode,
+        "fake.email" => &fake::Email,
         "generate_series" => &array::GenerateSeries,
-        _ => return Err(Error::UnknownFunction(name.to_owned())),
+        "date" => &time::Date,
+        "time" => &time::Time,
+        "extract" => &time::Extract,
+        "date_trunc" => &time::DateTrunc,
+        "add_months" => &time::AddMonths { months_per_unit: 1 },
+        "add_years" => &time::AddMonths { months_per_unit: 12 },
+        "last_day" => &time::LastDay,
+        "day_of_week" => &time::DayOfWeek,
+        "epoch" => &time::Epoch,
+        "to_timestamp" => &time::ToTimestamp,
+        "format_timestamp" => &time::FormatTimestamp,
+        _ => {
+            return unit_function_from_name(name, Unit::Characters)
+                .ok_or_else(|| Error::UnknownFunction(name.to_owned()))
+        }
+    })
+}
+
+/// Obtains a string function which counts the input in the given unit from its name.
+fn unit_function_from_name(name: &str, unit: functions::string::Unit) -> Option<&'static dyn Function> {
+    use functions::string::{self, Unit};
+
+    let by_unit = |characters: &'static dyn Function, octets: &'static dyn Function| match unit {
+        Unit::Characters => characters,
+        Unit::Octets => octets,
+    };
+
+    Some(match name {
+        "char_length" | "character_length" => by_unit(&string::Length(Unit::Characters), &string::Length(Unit::Octets)),
+        "trim" | "btrim" => by_unit(
+            &string::Trim {
+                unit: Unit::Characters,
+                leading: true,
+                trailing: true,
+            },
+            &string::Trim {
+                unit: Unit::Octets,
+                leading: true,
+                trailing: true,
+            },
+        ),
+        "ltrim" => by_unit(
+            &string::Trim {
+                unit: Unit::Characters,
+                leading: true,
+                trailing: false,
+            },
+            &string::Trim {
+                unit: Unit::Octets,
+                leading: true,
+                trailing: false,
+            },
+        ),
+        "rtrim" => by_unit(
+            &string::Trim {
+                unit: Unit::Characters,
+                leading: false,
+                trailing: true,
+            },
+            &string::Trim {
+                unit: Unit::Octets,
+                leading: false,
+                trailing: true,
+            },
+        ),
+        "lpad" => by_unit(
+            &string::Pad {
+                unit: Unit::Characters,
+                left: true,
+            },
+            &string::Pad {
+                unit: Unit::Octets,
+                left: true,
+            },
+        ),
+        "rpad" => by_unit(
+            &string::Pad {
+                unit: Unit::Characters,
+                left: false,
+            },
+            &string::Pad {
+                unit: Unit::Octets,
+                left: false,
+            },
+        ),
+        "strpos" => by_unit(
+            &string::Position {
+                unit: Unit::Characters,
+                haystack_first: true,
+            },
+            &string::Position {
+                unit: Unit::Octets,
+                haystack_first: true,
+            },
+        ),
+        "left" => by_unit(
+            &string::LeftRight {
+                unit: Unit::Characters,
+                left: true,
+            },
+            &string::LeftRight {
+                unit: Unit::Octets,
+                left: true,
+            },
+        ),
+        "right" => by_unit(
+            &string::LeftRight {
+                unit: Unit::Characters,
+                left: false,
+            },
+            &string::LeftRight {
+                unit: Unit::Octets,
+                left: false,
+            },
+        ),
+        "reverse" => by_unit(&string::Reverse(Unit::Characters), &string::Reverse(Unit::Octets)),
+        _ => return None,
     })
 }
 
@@ -832,6 +1205,8 @@ fn function_from_rule(rule: Rule) -> &'static dyn Function {
         Rule::op_bit_and => &functions::ops::Bitwise::And,
         Rule::op_bit_or => &functions::ops::Bitwise::Or,
         Rule::op_bit_xor => &functions::ops::Bitwise::Xor,
+        Rule::op_shl => &functions::ops::Shift::Left,
+        Rule::op_shr => &functions::ops::Shift::Right,
         r => unreachable!("Unexpected operator rule {:?}", r),
     }
 }
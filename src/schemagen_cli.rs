@@ -161,19 +161,14 @@ fn gen_serial_column(dialect: Dialect, _: &mut dyn RngCore) -> Column {
 
 const LOG2_10: f64 = 3.321_928_094_887_362;
 
-#[allow(clippy::cast_precision_loss)]
 fn gen_decimal_column(_: Dialect, rng: &mut dyn RngCore) -> Column {
-    let before = rng.gen_range(1, 19);
-    let after = rng.gen_range(0, 31);
-    let limit = "9".repeat(before);
+    let before: u32 = rng.gen_range(1, 19);
+    let after: u32 = rng.gen_range(0, 21);
     Column {
         ty: format!("decimal({}, {}) not null", before + after, after),
-        expr: format!(
-            "rand.range_inclusive(-{0}, {0}) || rand.regex('\\.[0-9]{{{1}}}')",
-            limit, after
-        ),
-        neg_log2_prob: LOG2_10 * (before + after) as f64 + 1.0,
-        average_len: (before + after) as f64 + 17.0 / 9.0,
+        expr: format!("rand.decimal({}, {})", before + after, after),
+        neg_log2_prob: LOG2_10 * f64::from(before + after) + 1.0,
+        average_len: f64::from(before + after) + 17.0 / 9.0,
         nullable: false,
     }
 }
@@ -1,23 +1,67 @@
 //! Values
 
-use chrono::{Duration, NaiveDateTime, TimeZone};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
 use chrono_tz::Tz;
 use num_traits::FromPrimitive;
 use std::{cmp::Ordering, convert::TryFrom, fmt, io::Write, ops, str::from_utf8, sync::Arc};
 
-use crate::error::Error;
+use crate::{
+    decimal::{Decimal, MAX_SCALE},
+    error::Error,
+    geometry::Geometry,
+};
 
 /// The string format of an SQL timestamp.
 pub const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
 
+/// The string format of an SQL date.
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// The string format of an SQL time of day.
+pub const TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+/// Number of microseconds in a day.
+const MICROSECONDS_PER_DAY: i64 = 86_400_000_000;
+
 /// Implementation of a number.
 #[derive(Copy, Clone, Debug)]
 enum N {
     Int(i128),
     Float(f64),
+    Decimal(Decimal),
+}
+
+impl N {
+    /// Converts an integer or decimal into a decimal. Returns `None` for floating point numbers.
+    fn to_decimal(self) -> Option<Decimal> {
+        match self {
+            Self::Int(v) => Some(v.into()),
+            Self::Float(_) => None,
+            Self::Decimal(d) => Some(d),
+        }
+    }
+
+    /// Converts both numbers into decimals if either of them is a decimal. A floating point number
+    /// is converted using its shortest representation, so `decimal('0.1') + 0.2` is exactly 0.3.
+    /// Returns `None` if neither number is a decimal, or the floating point number is not finite or
+    /// cannot be represented.
+    fn to_decimal_pair(self, other: Self) -> Option<(Decimal, Decimal)> {
+        match (self, other) {
+            (Self::Decimal(_), _) | (_, Self::Decimal(_)) => {
+                Some((Number(self).to_decimal()?, Number(other).to_decimal()?))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Converts the text of an integer or a decimal into a JSON number without going through `f64`.
+fn exact_json_number(text: &str) -> serde_json::Value {
+    text.parse::<serde_json::Number>()
+        .map_or(serde_json::Value::Null, serde_json::Value::Number)
 }
 
-/// An SQL number (could represent an integer or floating point number).
+/// An SQL number (could represent an integer, floating point number or exact decimal).
 #[derive(Copy, Clone, Debug)]
 pub struct Number(N);
 
@@ -29,6 +73,7 @@ impl fmt::Display for Number {
                 let mut output = ryu::Buffer::new();
                 f.write_str(output.format(v))
             }
+            N::Decimal(d) => d.fmt(f),
         }
     }
 }
@@ -39,6 +84,18 @@ impl Number {
         match self.0 {
             N::Int(v) => P::from_i128(v),
             N::Float(v) => P::from_f64(v),
+            N::Decimal(d) if d.scale() == 0 => P::from_i128(d.mantissa()),
+            N::Decimal(d) => P::from_f64(d.to_f64()),
+        }
+    }
+
+    /// Converts this number into a JSON value. Integers and decimals are written exactly, while
+    /// non-finite floating point numbers become `null`.
+    pub fn to_json(&self) -> serde_json::Value {
+        match self.0 {
+            N::Int(v) => exact_json_number(&v.to_string()),
+            N::Float(v) => v.into(),
+            N::Decimal(d) => exact_json_number(&d.to_string()),
         }
     }
 
@@ -48,6 +105,7 @@ impl Number {
             N::Int(v) => Some(v != 0),
             N::Float(v) if v.is_nan() => None,
             N::Float(v) => Some(v != 0.0),
+            N::Decimal(d) => Some(d.mantissa() != 0),
         }
     }
 
@@ -56,6 +114,9 @@ impl Number {
         Some(Self(match (self.0, other.0) {
             (N::Int(n), N::Int(d)) => N::Int(n.checked_div(d)?),
             (n, d) => {
+                if let Some((n, d)) = n.to_decimal_pair(d) {
+                    return Some(Self(N::Int(n.checked_div_integral(d)?)));
+                }
                 let d = f64::from(d);
                 if d == 0.0 || d.is_nan() {
                     return None;
@@ -70,6 +131,9 @@ impl Number {
         Some(Self(match (self.0, other.0) {
             (N::Int(n), N::Int(d)) => N::Int(n.checked_rem(d)?),
             (n, d) => {
+                if let Some((n, d)) = n.to_decimal_pair(d) {
+                    return Some(Self(N::Decimal(n.checked_rem(d)?)));
+                }
                 let d = f64::from(d);
                 if d == 0.0 || d.is_nan() {
                     return None;
@@ -78,6 +142,77 @@ impl Number {
             }
         }))
     }
+
+    /// Computes the absolute value of this number.
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self(match self.0 {
+            N::Int(v) => v
+                .checked_abs()
+                .map_or_else(|| N::Float(f64::from(self.0).abs()), N::Int),
+            N::Float(v) => N::Float(v.abs()),
+            N::Decimal(d) => d.checked_abs().map_or_else(|| N::Float(d.to_f64().abs()), N::Decimal),
+        })
+    }
+
+    /// Computes the sign of this number as -1, 0 or 1, keeping the integer or floating-point type.
+    #[must_use]
+    pub fn signum(&self) -> Self {
+        Self(match self.0 {
+            N::Int(v) => N::Int(v.signum()),
+            N::Float(v) if v == 0.0 || v.is_nan() => N::Float(v),
+            N::Float(v) => N::Float(v.signum()),
+            N::Decimal(d) => N::Int(d.mantissa().signum()),
+        })
+    }
+
+    /// Applies a rounding function to this number. Integers are returned unchanged, and decimals
+    /// are rounded exactly into integers.
+    #[must_use]
+    pub fn round_with(&self, f: impl FnOnce(f64) -> f64) -> Self {
+        match self.0 {
+            N::Int(_) => *self,
+            N::Float(v) => Self(N::Float(f(v))),
+            N::Decimal(d) => Self(d.rescale(0, f).map_or(N::Float(d.to_f64()), |d| N::Int(d.mantissa()))),
+        }
+    }
+
+    /// Rounds this number to the given number of digits after the decimal point, half away from
+    /// zero. A negative number of digits rounds to powers of 10. Decimals are rounded exactly and
+    /// remain decimals, while other numbers are rounded as floating point numbers.
+    #[must_use]
+    pub fn round_to_digits(&self, digits: i32) -> Self {
+        if let N::Decimal(d) = self.0 {
+            if let Some(d) = round_decimal_to_digits(d, digits) {
+                return Self(N::Decimal(d));
+            }
+        }
+        let scale = 10.0_f64.powi(digits);
+        Self(N::Float((f64::from(self.0) * scale).round() / scale))
+    }
+
+    /// Converts this number into an exact decimal. Floating point numbers are converted using their
+    /// shortest representation. Returns `None` if the number is not finite or cannot be represented.
+    pub fn to_decimal(&self) -> Option<Decimal> {
+        match self.0 {
+            N::Float(v) => Decimal::from_f64(v),
+            n => n.to_decimal(),
+        }
+    }
+
+    /// Raises this number to the power of the other number.
+    ///
+    /// The result is an integer only if both numbers are integers, the exponent is non-negative,
+    /// and the result does not overflow.
+    #[must_use]
+    pub fn pow(&self, other: &Self) -> Self {
+        if let (N::Int(b), N::Int(e)) = (self.0, other.0) {
+            if let Some(r) = u32::try_from(e).ok().and_then(|e| b.checked_pow(e)) {
+                return Self(N::Int(r));
+            }
+        }
+        Self(N::Float(f64::from(self.0).powf(f64::from(other.0))))
+    }
 }
 
 macro_rules! impl_from_int_for_number {
@@ -91,6 +226,27 @@ macro_rules! impl_from_int_for_number {
 }
 impl_from_int_for_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, bool);
 
+/// Rounds the decimal to the given number of digits after the decimal point, half away from zero.
+fn round_decimal_to_digits(d: Decimal, digits: i32) -> Option<Decimal> {
+    if digits >= 0 {
+        let scale = u8::try_from(digits).unwrap_or(u8::MAX).min(d.scale());
+        return d.rescale(scale, f64::round);
+    }
+    // round(x, -k) = round(x / 10^k) × 10^k
+    let shift = u8::try_from(digits.unsigned_abs()).ok()?;
+    let shifted = Decimal::new(d.mantissa(), d.scale().checked_add(shift).filter(|s| *s <= MAX_SCALE)?);
+    let rounded = shifted.rescale(0, f64::round)?;
+    Some(Decimal::new(
+        rounded.mantissa().checked_mul(10_i128.checked_pow(shift.into())?)?,
+        0,
+    ))
+}
+
+impl From<Decimal> for Number {
+    fn from(value: Decimal) -> Self {
+        Self(N::Decimal(value))
+    }
+}
 impl From<i128> for Number {
     fn from(value: i128) -> Self {
         Self(N::Int(value))
@@ -112,6 +268,7 @@ impl From<N> for f64 {
         match n {
             N::Int(i) => i as Self,
             N::Float(f) => f,
+            N::Decimal(d) => d.to_f64(),
         }
     }
 }
@@ -122,34 +279,54 @@ impl ops::Neg for Number {
         Self(match self.0 {
             N::Int(i) => N::Int(i.wrapping_neg()),
             N::Float(f) => N::Float(-f),
+            N::Decimal(d) => N::Decimal(d.wrapping_neg()),
         })
     }
 }
 
 macro_rules! impl_number_bin_op {
-    ($trait:ident, $fname:ident, $checked:ident) => {
-        impl ops::$trait for Number {
-            type Output = Self;
-            fn $fname(self, other: Self) -> Self {
-                if let (N::Int(a), N::Int(b)) = (self.0, other.0) {
-                    if let Some(c) = a.$checked(b) {
-                        return Self(N::Int(c));
-                    }
+    ($(#[$meta:meta])* $fname:ident, $checked:ident, $op:tt) => {
+        $(#[$meta])*
+        pub fn $fname(self, other: Self) -> Option<Self> {
+            if let (N::Int(a), N::Int(b)) = (self.0, other.0) {
+                if let Some(c) = a.$checked(b) {
+                    return Some(Self(N::Int(c)));
                 }
-                Self(N::Float(f64::from(self.0).$fname(f64::from(other.0))))
+            } else if let Some((a, b)) = self.0.to_decimal_pair(other.0) {
+                return a.$checked(b).map(|c| Self(N::Decimal(c)));
             }
+            Some(Self(N::Float(f64::from(self.0) $op f64::from(other.0))))
         }
     };
 }
 
-impl_number_bin_op!(Add, add, checked_add);
-impl_number_bin_op!(Sub, sub, checked_sub);
-impl_number_bin_op!(Mul, mul, checked_mul);
-
-impl ops::Div for Number {
-    type Output = Self;
-    fn div(self, other: Self) -> Self {
-        Self(N::Float(f64::from(self.0) / f64::from(other.0)))
+impl Number {
+    impl_number_bin_op!(
+        /// Adds two numbers. Returns `None` if a decimal result overflows.
+        checked_add,
+        checked_add,
+        +
+    );
+    impl_number_bin_op!(
+        /// Subtracts two numbers. Returns `None` if a decimal result overflows.
+        checked_sub,
+        checked_sub,
+        -
+    );
+    impl_number_bin_op!(
+        /// Multiplies two numbers. Returns `None` if a decimal result overflows.
+        checked_mul,
+        checked_mul,
+        *
+    );
+
+    /// Divides two numbers. Dividing two integers always produces a floating point number. Returns
+    /// `None` if a decimal result overflows or the divisor is a decimal zero.
+    pub fn checked_float_div(self, other: Self) -> Option<Self> {
+        if let Some((a, b)) = self.0.to_decimal_pair(other.0) {
+            return a.checked_div(b).map(|c| Self(N::Decimal(c)));
+        }
+        Some(Self(N::Float(f64::from(self.0) / f64::from(other.0))))
     }
 }
 
@@ -157,7 +334,10 @@ impl PartialEq for Number {
     fn eq(&self, other: &Self) -> bool {
         match (self.0, other.0) {
             (N::Int(a), N::Int(b)) => a == b,
-            (a, b) => f64::from(a) == f64::from(b),
+            (a, b) => match a.to_decimal_pair(b) {
+                Some((a, b)) => a == b,
+                _ => f64::from(a) == f64::from(b),
+            },
         }
     }
 }
@@ -166,7 +346,10 @@ impl PartialOrd for Number {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self.0, other.0) {
             (N::Int(a), N::Int(b)) => a.partial_cmp(&b),
-            (a, b) => f64::from(a).partial_cmp(&f64::from(b)),
+            (a, b) => match a.to_decimal_pair(b) {
+                Some((a, b)) => a.partial_cmp(&b),
+                _ => f64::from(a).partial_cmp(&f64::from(b)),
+            },
         }
     }
 }
@@ -204,10 +387,18 @@ pub enum Value {
     Bytes(Bytes),
     /// A timestamp. The `NaiveDateTime` field must be in the UTC time zone.
     Timestamp(NaiveDateTime, Tz),
+    /// A calendar date, independent of time zone.
+    Date(NaiveDate),
+    /// A time of day, independent of time zone.
+    Time(NaiveTime),
     /// A time interval, as multiple of microseconds.
     Interval(i64),
     /// An array of values.
     Array(Arc<[Value]>),
+    /// A geometry.
+    Geometry(Arc<Geometry>),
+    /// A JSON document.
+    Json(Arc<serde_json::Value>),
 }
 
 impl Default for Value {
@@ -251,6 +442,7 @@ impl fmt::Display for Value {
 
         let format = SqlFormat {
             escape_backslash: false,
+            mysql_axis_order: false,
         };
         let mut writer = Vec::new();
         format.write_value(&mut writer, self).map_err(|_| fmt::Error)?;
@@ -265,11 +457,17 @@ impl Value {
         Self::Timestamp(ts, tz)
     }
 
+    /// Creates a binary string value, even if the bytes happen to be valid UTF-8.
+    pub fn new_binary(bytes: Vec<u8>) -> Self {
+        Self::Bytes(Bytes { bytes, is_binary: true })
+    }
+
     /// Compares two values using the rules common among SQL implementations.
     ///
     /// * Comparing with NULL always return `None`.
     /// * Numbers and intervals are ordered by value.
     /// * Timestamps are ordered by its UTC value, ignoring time zone.
+    /// * Dates and times of day are ordered chronologically.
     /// * Strings are ordered by UTF-8 binary collation.
     /// * Arrays are ordered lexicographically.
     /// * Comparing between different types are inconsistent among database
@@ -280,6 +478,8 @@ impl Value {
             (Self::Number(a), Self::Number(b)) => a.partial_cmp(b),
             (Self::Bytes(a), Self::Bytes(b)) => a.bytes.partial_cmp(&b.bytes),
             (Self::Timestamp(a, _), Self::Timestamp(b, _)) => a.partial_cmp(b),
+            (Self::Date(a), Self::Date(b)) => a.partial_cmp(b),
+            (Self::Time(a), Self::Time(b)) => a.partial_cmp(b),
             (Self::Interval(a), Self::Interval(b)) => a.partial_cmp(b),
             (Self::Array(a), Self::Array(b)) => try_partial_cmp_by(a.iter(), b.iter(), |a, b| a.sql_cmp(b, name))?,
             _ => {
@@ -297,24 +497,32 @@ impl Value {
             Self::Null => Ordering::Equal,
             Self::Number(Number(N::Int(a))) => a.cmp(&0),
             Self::Number(Number(N::Float(a))) => a.partial_cmp(&0.0).unwrap_or(Ordering::Equal),
+            Self::Number(Number(N::Decimal(a))) => a.mantissa().cmp(&0),
             Self::Bytes(a) => true.cmp(&a.bytes.is_empty()),
-            Self::Timestamp(..) => Ordering::Greater,
+            Self::Timestamp(..) | Self::Date(_) | Self::Time(_) | Self::Geometry(_) | Self::Json(_) => {
+                Ordering::Greater
+            }
             Self::Interval(a) => a.cmp(&0),
             Self::Array(a) => true.cmp(&a.is_empty()),
         }
     }
 
     /// Adds two values using the rules common among SQL implementations.
-    pub fn sql_add(&self, other: &Self) -> Result<Self, Error> {
-        self.sql_add_named(other, "+")
+    ///
+    /// A date which becomes a timestamp, e.g. after adding an interval of hours or a time of day,
+    /// is interpreted in the given time zone.
+    pub fn sql_add(&self, other: &Self, time_zone: Tz) -> Result<Self, Error> {
+        self.sql_add_named(other, time_zone, "+")
     }
 
     /// Adds two values using the rules common among SQL implementations.
     ///
     /// The method uses a custom name when reporting errors.
-    pub(crate) fn sql_add_named(&self, other: &Self, name: &'static str) -> Result<Self, Error> {
+    pub(crate) fn sql_add_named(&self, other: &Self, time_zone: Tz, name: &'static str) -> Result<Self, Error> {
         Ok(match (self, other) {
-            (Self::Number(lhs), Self::Number(rhs)) => (*lhs + *rhs).into(),
+            (Self::Number(lhs), Self::Number(rhs)) => {
+                try_or_overflow!(lhs.checked_add(*rhs), "{} + {}", lhs, rhs).into()
+            }
             (Self::Timestamp(ts, tz), Self::Interval(dur)) | (Self::Interval(dur), Self::Timestamp(ts, tz)) => {
                 Self::Timestamp(
                     try_or_overflow!(
@@ -326,6 +534,16 @@ impl Value {
                     *tz,
                 )
             }
+            (Self::Date(date), Self::Interval(dur)) | (Self::Interval(dur), Self::Date(date)) => {
+                add_interval_to_date(*date, *dur, time_zone)?
+            }
+            (Self::Time(time), Self::Interval(dur)) | (Self::Interval(dur), Self::Time(time)) => {
+                Self::Time(time.overflowing_add_signed(Duration::microseconds(*dur)).0)
+            }
+            (Self::Date(date), Self::Time(time)) | (Self::Time(time), Self::Date(date)) => {
+                let local = date.and_time(*time);
+                Self::Timestamp(try_or_overflow!(from_local(local, time_zone), "{}", local), time_zone)
+            }
             (Self::Interval(a), Self::Interval(b)) => {
                 Self::Interval(try_or_overflow!(a.checked_add(*b), "{} + {}", a, b))
             }
@@ -339,9 +557,13 @@ impl Value {
     }
 
     /// Subtracts two values using the rules common among SQL implementations.
-    pub fn sql_sub(&self, other: &Self) -> Result<Self, Error> {
+    ///
+    /// A date which becomes a timestamp is interpreted in the given time zone.
+    pub fn sql_sub(&self, other: &Self, time_zone: Tz) -> Result<Self, Error> {
         Ok(match (self, other) {
-            (Self::Number(lhs), Self::Number(rhs)) => (*lhs - *rhs).into(),
+            (Self::Number(lhs), Self::Number(rhs)) => {
+                try_or_overflow!(lhs.checked_sub(*rhs), "{} - {}", lhs, rhs).into()
+            }
             (Self::Timestamp(ts, tz), Self::Interval(dur)) => Self::Timestamp(
                 try_or_overflow!(
                     ts.checked_sub_signed(Duration::microseconds(*dur)),
@@ -351,6 +573,24 @@ impl Value {
                 ),
                 *tz,
             ),
+            (Self::Date(date), Self::Interval(dur)) => {
+                add_interval_to_date(*date, try_or_overflow!(dur.checked_neg(), "-{}us", dur), time_zone)?
+            }
+            (Self::Time(time), Self::Interval(dur)) => {
+                Self::Time(time.overflowing_sub_signed(Duration::microseconds(*dur)).0)
+            }
+            (Self::Date(a), Self::Date(b)) => Self::Interval(try_or_overflow!(
+                a.signed_duration_since(*b).num_microseconds(),
+                "{} - {}",
+                a,
+                b
+            )),
+            (Self::Time(a), Self::Time(b)) => Self::Interval(try_or_overflow!(
+                a.signed_duration_since(*b).num_microseconds(),
+                "{} - {}",
+                a,
+                b
+            )),
             (Self::Interval(a), Self::Interval(b)) => {
                 Self::Interval(try_or_overflow!(a.checked_sub(*b), "{} + {}", a, b))
             }
@@ -366,9 +606,11 @@ impl Value {
     /// Multiplies two values using the rules common among SQL implementations.
     pub fn sql_mul(&self, other: &Self) -> Result<Self, Error> {
         Ok(match (self, other) {
-            (Self::Number(lhs), Self::Number(rhs)) => (*lhs * *rhs).into(),
+            (Self::Number(lhs), Self::Number(rhs)) => {
+                try_or_overflow!(lhs.checked_mul(*rhs), "{} * {}", lhs, rhs).into()
+            }
             (Self::Number(m), Self::Interval(dur)) | (Self::Interval(dur), Self::Number(m)) => {
-                let mult_res = *m * Number::from(*dur);
+                let mult_res = try_or_overflow!(m.checked_mul(Number::from(*dur)), "{} * {}us", m, dur);
                 Self::Interval(try_or_overflow!(mult_res.to::<i64>(), "{} microseconds", mult_res))
             }
             _ => {
@@ -388,9 +630,11 @@ impl Value {
             {
                 Self::Null
             }
-            (Self::Number(lhs), Self::Number(rhs)) => (*lhs / *rhs).into(),
+            (Self::Number(lhs), Self::Number(rhs)) => {
+                try_or_overflow!(lhs.checked_float_div(*rhs), "{} / {}", lhs, rhs).into()
+            }
             (Self::Interval(dur), Self::Number(d)) => {
-                let mult_res = Number::from(*dur) / *d;
+                let mult_res = try_or_overflow!(Number::from(*dur).checked_float_div(*d), "{}us / {}", dur, d);
                 Self::Interval(try_or_overflow!(mult_res.to::<i64>(), "{} microseconds", mult_res))
             }
             _ => {
@@ -432,9 +676,21 @@ impl Value {
                     )
                     .unwrap();
                 }
+                Self::Date(date) => {
+                    write!(&mut res.bytes, "{}", date.format(DATE_FORMAT)).unwrap();
+                }
+                Self::Time(time) => {
+                    write!(&mut res.bytes, "{}", time.format(TIME_FORMAT)).unwrap();
+                }
                 Self::Interval(interval) => {
                     write!(&mut res.bytes, "INTERVAL {} MICROSECOND", interval).unwrap();
                 }
+                Self::Geometry(geometry) => {
+                    write!(&mut res.bytes, "{geometry}").unwrap();
+                }
+                Self::Json(json) => {
+                    write!(&mut res.bytes, "{json}").unwrap();
+                }
                 Self::Array(_) => {
                     return Err(Error::InvalidArguments {
                         name: "||",
@@ -466,6 +722,40 @@ impl Value {
     }
 }
 
+/// Converts a local time of the time zone into a UTC timestamp.
+///
+/// If the local time is ambiguous, the earlier one is chosen. If the local time is skipped by a
+/// daylight saving time transition, it is moved forward by the length of the gap.
+pub(crate) fn from_local(local: NaiveDateTime, tz: Tz) -> Option<NaiveDateTime> {
+    if let Some(dt) = tz.from_local_datetime(&local).earliest() {
+        return Some(dt.naive_utc());
+    }
+    let before = tz.from_local_datetime(&(local - Duration::days(1))).earliest()?;
+    Some(local - before.naive_local().signed_duration_since(before.naive_utc()))
+}
+
+/// Adds a time interval to a date. The result is still a date if the interval is a whole number of
+/// days, otherwise it is a timestamp counted from the midnight of the date in the time zone.
+fn add_interval_to_date(date: NaiveDate, dur: i64, tz: Tz) -> Result<Value, Error> {
+    if dur % MICROSECONDS_PER_DAY == 0 {
+        let date = try_or_overflow!(
+            date.checked_add_signed(Duration::microseconds(dur)),
+            "{} + {}us",
+            date,
+            dur
+        );
+        return Ok(Value::Date(date));
+    }
+    let (ts, tz) = DateOrTimestamp::Date(date).to_timestamp(tz)?;
+    let ts = try_or_overflow!(
+        ts.checked_add_signed(Duration::microseconds(dur)),
+        "{} + {}us",
+        date,
+        dur
+    );
+    Ok(Value::Timestamp(ts, tz))
+}
+
 /// The error indicating the expected type.
 #[derive(Debug)]
 pub struct TryFromValueError(&'static str);
@@ -559,6 +849,17 @@ impl TryFrom<Value> for Vec<u8> {
     }
 }
 
+impl TryFrom<Value> for Bytes {
+    type Error = TryFromValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Bytes(bytes) => Ok(bytes),
+            _ => Err(TryFromValueError("bytes string")),
+        }
+    }
+}
+
 impl TryFrom<Value> for Option<bool> {
     type Error = TryFromValueError;
 
@@ -571,6 +872,52 @@ impl TryFrom<Value> for Option<bool> {
     }
 }
 
+impl TryFrom<Value> for (NaiveDateTime, Tz) {
+    type Error = TryFromValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Timestamp(ts, tz) => Ok((ts, tz)),
+            _ => Err(TryFromValueError("timestamp")),
+        }
+    }
+}
+
+/// A date or a timestamp, accepted by functions which work on both.
+#[derive(Debug)]
+pub(crate) enum DateOrTimestamp {
+    /// A calendar date.
+    Date(NaiveDate),
+    /// A timestamp in UTC, and its time zone.
+    Timestamp(NaiveDateTime, Tz),
+}
+
+impl DateOrTimestamp {
+    /// Converts this into a UTC timestamp and its time zone. A date is converted to its midnight in
+    /// the given time zone.
+    pub(crate) fn to_timestamp(&self, time_zone: Tz) -> Result<(NaiveDateTime, Tz), Error> {
+        Ok(match *self {
+            Self::Date(date) => {
+                let local = date.and_time(NaiveTime::MIN);
+                (try_or_overflow!(from_local(local, time_zone), "{}", local), time_zone)
+            }
+            Self::Timestamp(ts, tz) => (ts, tz),
+        })
+    }
+}
+
+impl TryFrom<Value> for DateOrTimestamp {
+    type Error = TryFromValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Date(date) => Ok(Self::Date(date)),
+            Value::Timestamp(ts, tz) => Ok(Self::Timestamp(ts, tz)),
+            _ => Err(TryFromValueError("date or timestamp")),
+        }
+    }
+}
+
 impl TryFrom<Value> for Arc<[Value]> {
     type Error = TryFromValueError;
 
@@ -582,6 +929,28 @@ impl TryFrom<Value> for Arc<[Value]> {
     }
 }
 
+impl TryFrom<Value> for Arc<Geometry> {
+    type Error = TryFromValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Geometry(g) => Ok(g),
+            _ => Err(TryFromValueError("geometry")),
+        }
+    }
+}
+
+impl TryFrom<Value> for Arc<serde_json::Value> {
+    type Error = TryFromValueError;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Json(j) => Ok(j),
+            _ => Err(TryFromValueError("JSON")),
+        }
+    }
+}
+
 impl<T: Into<Number>> From<T> for Value {
     fn from(value: T) -> Self {
         Self::Number(value.into())
@@ -606,6 +975,18 @@ impl From<Vec<u8>> for Value {
     }
 }
 
+impl From<Geometry> for Value {
+    fn from(g: Geometry) -> Self {
+        Self::Geometry(Arc::new(g))
+    }
+}
+
+impl From<serde_json::Value> for Value {
+    fn from(j: serde_json::Value) -> Self {
+        Self::Json(Arc::new(j))
+    }
+}
+
 impl From<Bytes> for Value {
     fn from(b: Bytes) -> Self {
         Self::Bytes(b)
@@ -617,3 +998,25 @@ impl<T: Into<Value>> From<Option<T>> for Value {
         value.map_or(Self::Null, T::into)
     }
 }
+
+#[test]
+fn test_decimal_arithmetic() {
+    let d = |s: &str| Value::Number(s.parse::<Decimal>().unwrap().into());
+    assert_eq!(d("0.1").sql_add(&Value::from(0.2), Tz::UTC).unwrap().to_string(), "0.3");
+    assert_eq!(Value::from(0.5).sql_mul(&d("1.1")).unwrap().to_string(), "0.55");
+    assert_eq!(
+        Value::from(0.1)
+            .sql_add(&Value::from(0.2), Tz::UTC)
+            .unwrap()
+            .to_string(),
+        "0.30000000000000004"
+    );
+    assert!(matches!(
+        d("1.0000000000000000000000000000000000001").sql_add(&Value::from(100), Tz::UTC),
+        Err(Error::IntegerOverflow(_))
+    ));
+    assert!(matches!(
+        d("10000000000000000000").sql_mul(&d("100000000000000000000")),
+        Err(Error::IntegerOverflow(_))
+    ));
+}
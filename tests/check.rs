@@ -1,9 +1,9 @@
 use anyhow::Error;
-use dbgen::cli::{run, Args};
+use dbgen::cli::{run, Args, FormatName};
 use diff::{lines, Result as DiffResult};
 use serde_json::from_reader;
 use std::{
-    fs::{read, read_dir, remove_file, File},
+    fs::{read, read_dir, read_to_string, remove_file, File},
     path::Path,
     str::from_utf8,
 };
@@ -65,3 +65,36 @@ fn main() -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Generates the `counter-rng` template into CSV files split in the given way, and concatenates the
+/// rows of each table in file order.
+fn run_counter_rng(files_count: u32, rows_count: u32) -> Result<Vec<String>, Error> {
+    let out_dir = tempdir()?;
+    let child_path = Path::new(file!()).with_file_name("data").join("counter-rng");
+    let mut args: Args = from_reader(File::open(child_path.join("flags.json"))?)?;
+    args.template = child_path.join("template.sql");
+    args.out_dir = out_dir.path().to_owned();
+    args.files_count = files_count;
+    args.rows_count = rows_count;
+    args.format = FormatName::Csv;
+    args.no_schemas = true;
+    run(args)?;
+
+    let mut tables = Vec::new();
+    for table in &["parent", "child"] {
+        let mut rows = String::new();
+        for i in 1..=files_count {
+            rows.push_str(&read_to_string(out_dir.path().join(format!("{}.{}.csv", table, i)))?);
+        }
+        tables.push(rows);
+    }
+    Ok(tables)
+}
+
+#[test]
+fn counter_rng_is_independent_of_file_split() {
+    let expected = run_counter_rng(3, 2).unwrap();
+    assert_eq!(run_counter_rng(2, 3).unwrap(), expected);
+    assert_eq!(run_counter_rng(1, 6).unwrap(), expected);
+    assert_eq!(run_counter_rng(6, 1).unwrap(), expected);
+}
@@ -0,0 +1,4 @@
+INSERT INTO child VALUES
+(1, 1, 20235),
+(1, 2, 685559),
+(2, 1, 423557);
@@ -0,0 +1,3 @@
+INSERT INTO child VALUES
+(3, 1, 926054),
+(4, 1, 514879);
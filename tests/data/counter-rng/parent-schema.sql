@@ -0,0 +1,2 @@
+CREATE TABLE parent ();
+
@@ -0,0 +1,3 @@
+INSERT INTO parent VALUES
+(1, 657912, 2, '63841b1e-8eef-4c19-ac42-f010aef73c98'),
+(2, 819490, 1, '829b0bdd-f2a4-480a-bbaa-46fdefa40684');
@@ -0,0 +1,3 @@
+INSERT INTO parent VALUES
+(3, 771030, 1, '4b8fd044-ce9c-429e-9203-5ca6de25c49d'),
+(4, 203918, 1, '855b402b-1da1-4946-8a0e-97f887a7aaf4');
@@ -0,0 +1,3 @@
+INSERT INTO parent VALUES
+(5, 353409, 0, 'e7ec4613-c157-4df1-a36e-975c89c7ae4e'),
+(6, 690036, 0, 'f62293c6-d661-4153-ad37-b95a0972fac4');
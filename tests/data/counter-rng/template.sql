@@ -0,0 +1,13 @@
+create table parent(
+    {{ rownum }}
+    {{ rand.range(0, 1000000) }}
+    {{ @n := rand.range_inclusive(0, 2) }}
+    {{ rand.uuid() }}
+);
+
+{{ for each row of parent generate @n rows of child }}
+create table child(
+    {{ rownum }}
+    {{ subrownum }}
+    {{ rand.range(0, 1000000) }}
+);
@@ -0,0 +1,2 @@
+INSERT INTO result VALUES
+('2024-01-01 01:00:00', 1, '2023-12-31 22:30:00', '2024-01-01 12:34:56', '2024-01-01 04:34:56', 1704038400, 1704038400, '2024-01-01 00:00 CST', '2024-02-29', '2025-02-28', '2024-02-01', '2024-02-15', '2024-02-29', 1);
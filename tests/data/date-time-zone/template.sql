@@ -0,0 +1,16 @@
+CREATE TABLE result (
+    {{ DATE '2024-01-01' + INTERVAL 1 HOUR }}
+    {{ DATE '2024-01-01' + INTERVAL 1 HOUR < TIMESTAMP '2024-01-01 02:00:00' }}
+    {{ DATE '2024-01-01' - INTERVAL 90 MINUTE }}
+    {{ DATE '2024-01-01' + TIME '12:34:56' }}
+    {{ (DATE '2024-01-01' + TIME '12:34:56') AT TIME ZONE 'UTC' }}
+    {{ epoch(DATE '2024-01-01') }}
+    {{ extract(epoch FROM DATE '2024-01-01') }}
+    {{ format_timestamp(DATE '2024-01-01', '%Y-%m-%d %H:%M %Z') }}
+    {{ add_months(DATE '2024-01-31', 1) }}
+    {{ add_years(DATE '2024-02-29', 1) }}
+    {{ date_trunc('month', DATE '2024-02-15') }}
+    {{ date_trunc('hour', DATE '2024-02-15') }}
+    {{ last_day(DATE '2024-02-15') }}
+    {{ date_trunc('month', DATE '2024-02-15') = DATE '2024-02-01' }}
+);
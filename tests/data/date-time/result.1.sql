@@ -0,0 +1,5 @@
+INSERT INTO result VALUES
+('2024-02-28', '01:19:00', '2024-03-06', '2024-02-27 22:30:00', '01:18:58.500000', '58 00:00:00', '2024-02-28 01:19:00', 1, '2024-02-29', '12:34:56.789000', 2024, 'on 2024-02-28 at 01:19:00'),
+('2024-02-27', '23:38:00', '2024-03-05', '2024-02-26 22:30:00', '23:37:58.500000', '57 00:00:00', '2024-02-27 23:38:00', 1, '2024-02-29', '12:34:56.789000', 2024, 'on 2024-02-27 at 23:38:00'),
+('2024-02-28', '00:31:00', '2024-03-06', '2024-02-27 22:30:00', '00:30:58.500000', '58 00:00:00', '2024-02-28 00:31:00', 1, '2024-02-29', '12:34:56.789000', 2024, 'on 2024-02-28 at 00:31:00'),
+('2024-02-27', '01:13:00', '2024-03-05', '2024-02-26 22:30:00', '01:12:58.500000', '57 00:00:00', '2024-02-27 01:13:00', 1, '2024-02-29', '12:34:56.789000', 2024, 'on 2024-02-27 at 01:13:00');
@@ -0,0 +1,14 @@
+CREATE TABLE result (
+    {{ @d := DATE '2024-02-27' + rand.range(0, 5) * INTERVAL 1 DAY }}
+    {{ @t := TIME '23:30:00' + rand.range(0, 120) * INTERVAL 1 MINUTE }}
+    {{ @d + INTERVAL 1 WEEK }}
+    {{ @d - INTERVAL 90 MINUTE }}
+    {{ @t - INTERVAL 1.5 SECOND }}
+    {{ @d - DATE '2024-01-01' }}
+    {{ @d + @t }}
+    {{ @d < DATE '2024-03-01' }}
+    {{ date(TIMESTAMP WITH TIME ZONE '2024-03-01 02:00:00 Asia/Tokyo' AT TIME ZONE 'UTC') }}
+    {{ time(TIMESTAMP '2024-03-01 12:34:56.789') }}
+    {{ extract(year FROM @d) }}
+    {{ 'on ' || @d || ' at ' || @t }}
+);
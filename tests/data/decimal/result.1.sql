@@ -0,0 +1,5 @@
+INSERT INTO result VALUES
+(1, 8289.19, 0.0423, 0.3, 0.3, 1.0500, 2.35, 1, -3, 0.0000000000000000000000000000000000001),
+(2, -2409.23, 0.2571, 0.3, 0.3, 2.1000, 4.69, 1, -5, 0.0000000000000000000000000000000000001),
+(3, 7184.94, 0.0053, 0.3, 0.3, 3.1500, 7.04, 1, -8, 0.0000000000000000000000000000000000001),
+(4, 5464.26, 0.3200, 0.3, 0.3, 4.2000, 9.38, 1, -10, 0.0000000000000000000000000000000000001);
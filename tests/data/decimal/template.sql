@@ -0,0 +1,12 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.decimal(6, 2) }}
+    {{ decimal(rand.range_inclusive(0, 5000)) / 10000 }}
+    {{ decimal('0.1') + decimal(0.2) }}
+    {{ decimal('0.1') + 0.2 }}
+    {{ decimal(rownum, 2) * decimal('1.05') }}
+    {{ round(decimal('2.345') * rownum, 2) }}
+    {{ decimal('-1.25') < decimal(-1) }}
+    {{ floor(decimal('-2.5') * rownum) }}
+    {{ decimal('1.0000000000000000000000000000000000001') - 1 }}
+);
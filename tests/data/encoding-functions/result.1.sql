@@ -0,0 +1,2 @@
+INSERT INTO result VALUES
+('646267656e', X'646267656E', 'dbgen', X'FF00', 'ZGJnZW4h', '/wD+', 'dbgen!', 'a%20b%26c%3Dd%2F%C3%A9~', 'a b&c=d/é~', '"my ""table"""', '''it''''s''', X'27FF272727', X'274127', X'2241222222');
@@ -0,0 +1,16 @@
+create table result (
+    {{ to_hex('dbgen') }}
+    {{ from_hex('646267656E') }}
+    {{ convert_from(from_hex('646267656E')) }}
+    {{ from_hex('ff00') }}
+    {{ to_base64('dbgen!') }}
+    {{ to_base64(from_hex('ff00fe')) }}
+    {{ convert_from(from_base64('ZGJnZW4h')) }}
+    {{ url_encode('a b&c=d/é~') }}
+    {{ convert_from(url_decode('a+b%26c%3Dd%2F%C3%A9~')) }}
+    {{ quote_ident('my "table"') }}
+    {{ quote_literal('it''s') }}
+    {{ quote_literal(from_hex('ff27')) }}
+    {{ quote_literal(from_hex('41')) }}
+    {{ quote_ident(from_hex('4122')) }}
+);
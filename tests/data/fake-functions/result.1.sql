@@ -0,0 +1,9 @@
+INSERT INTO result VALUES
+(1, 'Margaret', 'Brown', 'margaret.brown@example.com', '435 Maple Avenue Apt. 38', 'Franklin', '878-610-1998', 'Lewis, Taylor and Thompson', 'KE', 'Lukas Schmidt', 'juergen.schmidt@example.com', 'Gartenstraße 55a', '07621 089005', 'Bertrand SARL', '東京都千代田区緑町2丁目44-3', 'user@example.com'),
+(2, 'Daniel', 'Smith', 'daniel.smith@example.com', '180 Elm Street Apt. 29', 'Seattle', '599-485-1944', 'Hernandez Corp.', 'BE', 'Jonas Braun', 'juergen.braun@example.net', 'Schillerstraße 47', '0745 4325976', 'Faure et Fils', 'さいたま市南町3丁目58-8', 'user@example.com'),
+(3, 'Margaret', 'King', 'margaret.king@example.org', '92 River Road', 'San Diego', '(552) 855-7889', 'Nguyen LLC', 'NO', 'Emilia Wolf', 'juergen.wolf@example.com', 'Goethestraße 22', '06103 599861', 'Durand et Morel', '仙台市中央1丁目50-6', 'user@example.org'),
+(4, 'Richard', 'Perez', 'richard.perez@example.org', '12 Oak Street', 'New York', '(994) 371-9647', 'Flores Holdings', 'AT', 'Björn Schneider', 'juergen.schneider@example.com', 'Gartenstraße 5', '+49 174 60573853', 'Dubois et Laurent', '福岡市東町9丁目26-8', 'user@example.com'),
+(5, 'Patricia', 'Johnson', 'patricia.johnson@example.org', '613 Pine Street Apt. 16', 'Nashville', '(413) 608-6591', 'Flores, Williams and Harris', 'SG', 'Felix Klein', 'juergen.klein@example.net', 'Wiesenweg 4', '+49 865 2469027', 'Dupont et Fils', '東京都新宿区旭町1-23-54', 'user@example.com'),
+(6, 'John', 'Brown', 'john.brown@example.com', '267 Highland Avenue Apt. 86', 'Madison', '(816) 685-3156', 'Wright-Jackson', 'MX', 'Maximilian Schneider', 'juergen.schneider@example.org', 'Am Markt 2', '06960 034356', 'Moreau et Girard', '横浜市元町6丁目98-2', 'user@example.org'),
+(7, 'Paul', 'Davis', 'paul.davis@example.net', '13 River Road', 'Madison', '888-832-8272', 'Flores-Taylor', 'BR', 'Sophia Braun', 'juergen.braun@example.com', 'Wiesenweg 13', '02307 158354', 'Bernard SARL', '東京都新宿区中央6-72-47', 'user@example.com'),
+(8, 'Elizabeth', 'Perez', 'elizabeth.perez@example.net', '644 Sunset Boulevard', 'Chicago', '+1 395-175-9115', 'White Group', 'SA', 'Günther Becker', 'juergen.becker@example.org', 'Jahnstraße 67', '+49 354 9023184', 'Leroy SARL', '北九州市中央5丁目50-5', 'user@example.net');
@@ -0,0 +1,18 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ @first := fake.first_name() }}
+    {{ @last := fake.last_name('en_US') }}
+    {{ fake.email(@first, @last) }}
+    {{ fake.street_address() }}
+    {{ fake.city() }}
+    {{ fake.phone() }}
+    {{ fake.company() }}
+    {{ fake.country_code() }}
+    {{ fake.first_name('de_DE') || ' ' || (@last := fake.last_name('de_DE')) }}
+    {{ fake.email('Jürgen', @last) }}
+    {{ fake.street_address('de_DE') }}
+    {{ fake.phone('de_DE') }}
+    {{ fake.company('fr_FR') }}
+    {{ fake.street_address('ja_JP') }}
+    {{ fake.email(fake.first_name('ja_JP'), fake.last_name('ja_JP')) }}
+);
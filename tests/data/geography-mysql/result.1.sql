@@ -0,0 +1,3 @@
+INSERT INTO result VALUES
+(ST_GeomFromText('POINT(139.6917 35.6895)', 4326, 'axis-order=long-lat'), ST_GeomFromText('POLYGON((-122.39073799018747 37.80241744711074,-122.40035652972573 37.805354672036664,-122.4072373180072 37.797776381646926,-122.39871451343313 37.79079307281521,-122.39073799018747 37.80241744711074))', 4326, 'axis-order=long-lat'), ST_GeomFromText('POINT(139.6917 35.6895)')),
+(ST_GeomFromText('POINT(139.6917 35.6895)', 4326, 'axis-order=long-lat'), ST_GeomFromText('POLYGON((-122.39248209789527 37.80006375438496,-122.4046790302935 37.806733600723916,-122.40956669595626 37.799592231209274,-122.3954907359794 37.79420879500237,-122.39248209789527 37.80006375438496))', 4326, 'axis-order=long-lat'), ST_GeomFromText('POINT(139.6917 35.6895)'));
@@ -0,0 +1,5 @@
+CREATE TABLE result (
+    {{ geography(st_point(139.6917, 35.6895)) }}
+    {{ geography(rand.polygon(-122.4, 37.8, 0.01, 4)) }}
+    {{ st_point(139.6917, 35.6895) }}
+);
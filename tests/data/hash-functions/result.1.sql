@@ -0,0 +1,2 @@
+INSERT INTO result VALUES
+(X'D41D8CD98F00B204E9800998ECF8427E', '9e107d9d372bb6826bd81d3542a419d6', 'a9993e364706816aba3e25717850c26c9cd0d89d', 'ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad', 'ddaf35a193617aba', 1, NULL, 1095738169, 3826002220, 12638187200555641996, 4952883123889572249, 13738734796240226568, '646267656e');
@@ -0,0 +1,15 @@
+create table result (
+    {{ md5('') }}
+    {{ to_hex(md5('The quick brown fox jumps over the lazy dog')) }}
+    {{ to_hex(sha1('abc')) }}
+    {{ to_hex(sha256('abc')) }}
+    {{ substring(to_hex(sha512('abc')) from 1 for 16) }}
+    {{ to_hex(md5(12345)) = to_hex(md5('12345')) }}
+    {{ md5(NULL) }}
+    {{ crc32('The quick brown fox jumps over the lazy dog') }}
+    {{ fnv1a32('a') }}
+    {{ fnv1a64('a') }}
+    {{ xxh64('abc') }}
+    {{ xxh64('abc', 1) }}
+    {{ to_hex('dbgen') }}
+);
@@ -0,0 +1,5 @@
+INSERT INTO result VALUES
+(1, '{"extra":[null,true,1.5,false],"id":1,"name":"user \"1\"","score":0.25,"tags":["it''s","green"]}', 'green', '[null,true,1.5,false]', NULL, '{"id":1,"name":"user \"1\"","score":0.25,"tags":{"n":2}}', '{"amount":1.10,"precise":12345678901234567890.0123456789012345678}'),
+(2, '{"extra":[null,true,1.5,true],"id":2,"name":"user \"2\"","score":0.5,"tags":["green","blue"]}', 'blue', '[null,true,1.5,true]', NULL, '{"id":2,"name":"user \"2\"","score":0.5,"tags":{"n":2}}', '{"amount":2.20,"precise":12345678901234567890.0123456789012345678}'),
+(3, '{"extra":[null,true,1.5,true],"id":3,"name":"user \"3\"","score":0.75,"tags":["it''s","green"]}', 'green', '[null,true,1.5,true]', NULL, '{"id":3,"name":"user \"3\"","score":0.75,"tags":{"n":2}}', '{"amount":3.30,"precise":12345678901234567890.0123456789012345678}'),
+(4, '{"extra":[null,true,1.5,true],"id":4,"name":"user \"4\"","score":1.0,"tags":["red","it''s"]}', 'it''s', '[null,true,1.5,true]', NULL, '{"id":4,"name":"user \"4\"","score":1.0,"tags":{"n":2}}', '{"amount":4.40,"precise":12345678901234567890.0123456789012345678}');
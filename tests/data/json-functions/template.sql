@@ -0,0 +1,10 @@
+{{ @tags := array['red', 'green', 'blue', 'it''s'] }}
+CREATE TABLE result (
+    {{ rownum }}
+    {{ @doc := json_object('id', rownum, 'name', 'user "' || rownum || '"', 'tags', rand.sample(@tags, 2), 'score', rownum / 4, 'extra', json_array(NULL, json_bool(true), 1.5, json_bool(rownum > 1))) }}
+    {{ json_extract(@doc, '$.tags[1]') }}
+    {{ json_extract(@doc, '$.extra') }}
+    {{ json_extract(@doc, '$.missing') }}
+    {{ json_merge(@doc, '{"extra": null, "tags": {"n": 2}}') }}
+    {{ json_object('amount', decimal('1.10') * rownum, 'precise', decimal('12345678901234567890.0123456789012345678')) }}
+);
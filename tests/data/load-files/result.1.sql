@@ -0,0 +1,7 @@
+INSERT INTO result VALUES
+(1, 'Oslo', 'red', ARRAY['007', '012', '100'], ''),
+(2, 'Vaduz', 'blue', ARRAY['007', '012', '100'], 'say "hi"'),
+(3, 'Oslo', 'red', ARRAY['007', '012', '100'], 'primary, warm'),
+(4, 'Tallinn', 'blue', ARRAY['007', '012', '100'], ''),
+(5, 'Vaduz', 'red', ARRAY['007', '012', '100'], 'say "hi"'),
+(6, 'Vaduz', 'red', ARRAY['007', '012', '100'], 'primary, warm');
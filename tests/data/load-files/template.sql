@@ -0,0 +1,8 @@
+{{ @cities := load_lines('cities.txt'); @freq := load_csv('freq.csv', 'value', 'weight') }}
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.choice(@cities) }}
+    {{ rand.weighted(@freq[1], @freq[2]) }}
+    {{ load_csv('freq.csv', 'sku') }}
+    {{ load_csv('freq.csv', 'note')[mod(rownum, 3) + 1] }}
+);
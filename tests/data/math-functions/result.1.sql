@@ -0,0 +1,2 @@
+INSERT INTO result VALUES
+(7, 2.5, 1.7014118346046923e38, -1, 0, 1.0, 0.0, -3.0, -2.0, -2.0, 7, 1024, 1.7014118346046923e38, 0.5, 2.0, 4.0, NULL, 1.0, 0.0, NULL, 3.0, 10.0, NULL, 0.0, 1.0, 1, NULL, 1024, -128, 4, 8, 0, 1);
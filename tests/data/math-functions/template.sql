@@ -0,0 +1,39 @@
+create table result (
+    {{ abs(-7) }}
+    {{ abs(-2.5) }}
+    {{ abs(-1 << 127) }}
+    {{ sign(-7) }}
+    {{ sign(0) }}
+    {{ sign(2.5) }}
+    {{ sign(0.0) }}
+
+    {{ floor(-2.5) }}
+    {{ ceil(-2.5) }}
+    {{ trunc(-2.5) }}
+    {{ floor(7) }}
+
+    {{ power(2, 10) }}
+    {{ power(2, 127) }}
+    {{ power(2, -1) }}
+    {{ power(4, 0.5) }}
+    {{ sqrt(16) }}
+    {{ sqrt(-1) }}
+    {{ exp(0) }}
+    {{ ln(1) }}
+    {{ ln(0) }}
+    {{ log10(1000) }}
+    {{ log(2, 1024) }}
+    {{ log(1, 5) }}
+
+    {{ sin(0) }}
+    {{ cos(0) }}
+    {{ atan2(1, 1) * 4 = pi() }}
+    {{ asin(2) }}
+
+    {{ 1 << 10 }}
+    {{ -1024 >> 3 }}
+    {{ 1 << 4 >> 2 }}
+    {{ 1 + 1 << 2 & 12 }}
+    {{ 1 >> 200 }}
+    {{ 3 << 2 < 13 }}
+);
@@ -0,0 +1,7 @@
+INSERT INTO result VALUES
+(1, '202.221.233.63', '10.20.30.37', 169090597, '10.20.31.37', '192.168.173.174', 'fb64:9965:15ab:b139:6a50:ec4:ea1a:401', '653734c361296ee2d92befca12c428e9', 'ae:4b:6e:d5:49:da', 'c48c8decaee1', NULL, NULL, X'41424302'),
+(2, '32.209.219.53', '10.20.30.32', 169090592, '10.20.31.32', '192.168.26.82', '9112:ae79:d7a4:40f4:eae1:f450:ea42:738d', '670ea1b5c60c1eb6b5b71f8377cb33e2', '68:f5:c3:1b:df:ce', '5693c97c795d', NULL, NULL, X'41424301'),
+(3, '147.219.211.30', '10.20.30.38', 169090598, '10.20.31.38', '192.168.137.244', '2997:c729:3d99:c3ae:b107:6f47:c4ee:e738', '0baf62c8f6920eddd01d31f1177ba3c6', 'ec:a3:8e:58:94:81', 'aa9bb362ef21', NULL, NULL, X'41424301'),
+(4, '106.99.221.188', '10.20.30.36', 169090596, '10.20.31.36', '192.168.195.200', '248c:df66:be8a:6ba3:1105:be5f:6a20:a650', '08a553c39799fe8e1d64a970cabceba6', '2c:5a:d2:c2:d7:f4', '6a7bae931254', NULL, NULL, X'41424300'),
+(5, '227.175.241.28', '10.20.30.44', 169090604, '10.20.31.44', '192.168.19.165', '1cc3:30d2:439e:8490:7c9:fa:de87:97bc', 'a6e5cc380e7517179879b051f1173185', 'e2:55:c4:a7:aa:09', '22b75ab222ad', NULL, NULL, X'41424301'),
+(6, '150.105.7.255', '10.20.30.34', 169090594, '10.20.31.34', '192.168.15.61', '987b:d3ac:8a0c:d8f6:1f73:f38:c962:898c', '5be8b55165f222e7b006eb1e66ebc8de', '2a:da:f4:70:13:9a', 'dac61c1690d3', NULL, NULL, X'41424302');
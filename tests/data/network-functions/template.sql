@@ -0,0 +1,15 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.ipv4() }}
+    {{ @ip := rand.ipv4_in('10.20.30.40/28') }}
+    {{ inet_aton(@ip) }}
+    {{ inet_ntoa(inet_aton(@ip) + 256) }}
+    {{ inet_ntoa(rand.ipv4_in('192.168.0.0/16', TRUE)) }}
+    {{ rand.ipv6() }}
+    {{ to_hex(rand.ipv6(TRUE)) }}
+    {{ rand.mac() }}
+    {{ to_hex(rand.mac(TRUE)) }}
+    {{ inet_aton('999.1.2.3') }}
+    {{ inet_ntoa(4294967296) }}
+    {{ rand.ipv4_in('65.66.67.0/30', TRUE) }}
+);
@@ -0,0 +1,6 @@
+INSERT INTO result VALUES
+(5, NULL, 104183),
+(6, 104183, 896009);
+INSERT INTO result VALUES
+(7, 896009, 656387),
+(8, 656387, 691005);
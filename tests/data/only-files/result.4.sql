@@ -0,0 +1,6 @@
+INSERT INTO result VALUES
+(13, NULL, 144219),
+(14, 144219, 316815);
+INSERT INTO result VALUES
+(15, 316815, 203398),
+(16, 203398, 110010);
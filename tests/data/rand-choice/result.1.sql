@@ -0,0 +1,13 @@
+INSERT INTO result VALUES
+(1, 'DE', 'US', 'always', ARRAY['DE', 'BR']),
+(2, 'JP', 'US', 'always', ARRAY['BR', 'DE']),
+(3, 'BR', 'US', 'always', ARRAY['US', 'BR']),
+(4, 'JP', 'US', 'always', ARRAY['JP', 'BR']),
+(5, 'JP', 'US', 'always', ARRAY['DE', 'JP']),
+(6, 'US', 'US', 'always', ARRAY['DE', 'BR']),
+(7, 'JP', 'DE', 'always', ARRAY['BR', 'DE']),
+(8, 'US', 'JP', 'always', ARRAY['JP', 'US']),
+(9, 'US', 'DE', 'always', ARRAY['DE', 'JP']),
+(10, 'JP', 'US', 'always', ARRAY['JP', 'US']),
+(11, 'DE', 'US', 'always', ARRAY['JP', 'DE']),
+(12, 'US', 'US', 'always', ARRAY['DE', 'BR']);
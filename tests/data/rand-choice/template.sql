@@ -0,0 +1,8 @@
+{{ @countries := array['US', 'DE', 'JP', 'BR'] }}
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.choice(@countries) }}
+    {{ rand.weighted(@countries, array[62, 8, 25, 5]) }}
+    {{ rand.weighted(array['never', 'always'], array[0, 0.5]) }}
+    {{ rand.sample(@countries, 2) }}
+);
@@ -0,0 +1,5 @@
+INSERT INTO result VALUES
+(1, X'3FA0AC01C43965E9', X'CAE2C3CAE2C3CAE2C3CAE2C3', 'P\07qi!}Oiiq\,%)', 'vwpULcvwpULcvwpULcvw', 1000),
+(2, X'D9D7965DF83E633E', X'306E20306E20306E20306E20', 'u7g5d}FikyuZ>(B1', '!_-Gv"!_-Gv"!_-Gv"!_', 1000),
+(3, X'5CC101F63448EF18', X'CF2729CF2729CF2729CF2729', ',ZV}[2<]Hth|JPEI', 'g81Msng81Msng81Msng8', 1000),
+(4, X'D5FE0EEEF0765B76', X'F9EF35F9EF35F9EF35F9EF35', 'I3WA+N+QG[Y)s''%h', '|[ST|Y|[ST|Y|[ST|Y|[', 1000);
@@ -0,0 +1,8 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.bytes(8) }}
+    {{ rand.bytes(12, 0.25) }}
+    {{ rand.text(16) }}
+    {{ rand.text(20, 0.3) }}
+    {{ octet_length(rand.text(1000, 0.5)) }}
+);
@@ -0,0 +1,7 @@
+INSERT INTO result VALUES
+(1, 85.76, 5.29, 0.1879, 'Oslo', 0.78),
+(2, 95.09, 4.67, 0.1062, 'Oslo', 2.27),
+(3, 82.73, 3.72, 0.6465, 'Bergen', 11.06),
+(4, 61.94, 3.65, 0.9017, 'Tromsø', 20.27),
+(5, 116.58, 6.41, 0.513, 'Bergen', 3.91),
+(6, 104.73, 3.62, 0.8126, 'Tromsø', 15.48);
@@ -0,0 +1,8 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ @v := rand.mvnormal(array[100, 5], array[array[400, 30], array[30, 4]]); round(@v[1], 2) }}
+    {{ round(@v[2], 2) }}
+    {{ @u := rand.copula(array[array[1, 0.9], array[0.9, 1]]); round(@u[1], 4) }}
+    {{ @cities := array['Oslo', 'Bergen', 'Tromsø']; @cities[floor(@u[1] * 3) + 1] }}
+    {{ round(-ln(1 - @u[2]) * 10, 2) }}
+);
@@ -0,0 +1,21 @@
+INSERT INTO result VALUES
+(1, 3, 2, 2, 81.0, 1.24, 2.346, 0.572, 0.309, 1.288, 5.744, -5.929),
+(2, 3, 4, 2, 93.0, 5.746, 2.003, 0.27, 1.01, 1.594, 2.387, 2.263),
+(3, 7, 2, 15, 92.0, 0.329, 13.457, 0.425, 0.213, 1.432, 3.719, -0.279),
+(4, 4, 3, 1, 103.0, 1.059, 7.949, 0.174, 1.117, 1.02, 7.359, 0.424),
+(5, 5, 3, 0, 108.0, 3.738, 3.197, 0.237, 1.343, 1.241, 5.288, -0.706),
+(6, 3, 4, 2, 89.0, 2.93, 6.599, 0.346, 0.332, 2.028, 4.069, -3.19),
+(7, 3, 2, 0, 89.0, 1.721, 9.088, 0.195, 1.797, 1.024, 1.261, -1.345),
+(8, 1, 7, 1, 85.0, 3.207, 9.121, 0.512, 2.092, 2.314, 1.485, -0.505),
+(9, 3, 3, 1, 95.0, 0.502, 1.402, 0.322, 0.043, 1.07, 9.224, -0.397),
+(10, 4, 3, 1, 113.0, 1.968, 3.611, 0.472, 0.553, 1.07, 3.794, 0.665),
+(11, 2, 4, 1, 108.0, 2.615, 4.639, 0.091, 1.769, 1.087, 5.338, -1.655),
+(12, 5, 4, 0, 79.0, 4.162, 2.458, 0.168, 0.373, 1.062, 2.081, 0.328),
+(13, 3, 4, 6, 118.0, 2.569, 6.276, 0.221, 1.253, 1.143, 3.639, -4.156),
+(14, 4, 2, 8, 102.0, 5.2, 9.213, 0.115, 0.085, 1.306, 5.287, 0.408),
+(15, 4, 3, 4, 110.0, 2.366, 14.246, 0.218, 0.404, 1.021, 4.432, 0.296),
+(16, 3, 3, 1, 111.0, 2.298, 2.969, 0.126, 0.041, 2.377, 0.457, -1.492),
+(17, 3, 2, 5, 107.0, 0.297, 2.432, 0.186, 1.278, 1.44, 6.762, -0.23),
+(18, 1, 1, 2, 107.0, 0.871, 2.215, 0.275, 0.424, 1.455, 3.666, 0.131),
+(19, 3, 5, 0, 101.0, 0.997, 1.529, 0.169, 0.332, 1.28, 2.631, 2.881),
+(20, 5, 5, 1, 88.0, 2.18, 6.749, 0.188, 1.094, 1.003, 2.04, 1.323);
@@ -0,0 +1,14 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.poisson(4.5) }}
+    {{ rand.binomial(10, 0.3) }}
+    {{ rand.geometric(0.25) }}
+    {{ round(rand.normal(100, 15)) }}
+    {{ round(rand.exponential(0.5), 3) }}
+    {{ round(rand.gamma(2, 3), 3) }}
+    {{ round(rand.beta(2, 5), 3) }}
+    {{ round(rand.weibull(1, 1.5), 3) }}
+    {{ round(rand.pareto(1, 3), 3) }}
+    {{ round(rand.triangular(0, 10, 2), 3) }}
+    {{ round(rand.cauchy(0, 1), 3) }}
+);
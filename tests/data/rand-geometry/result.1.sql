@@ -0,0 +1,5 @@
+INSERT INTO result VALUES
+(1, ST_GeomFromText('POINT(3.250718491134346 9.14459467324999)'), 'POINT(0.3386042652794004 0.29322264528088576)', ST_GeomFromText('POINT(-43.36615091529737 2.56363049875182)', 4326), ST_GeomFromText('LINESTRING(1 2,1.1911144028063945 2.38477477944443,1.4423523840156076 2.4018452404631003)'), ST_GeomFromText('POLYGON((106.7336007239177 104.67903029349107,99.59223120927699 109.56669595624842,94.20879500237507 95.49073597938407,104.43358127446503 93.62400226648685,106.7336007239177 104.67903029349107))'), X'0101000000000000000000F03F000000000000E0BF'),
+(2, ST_GeomFromText('POINT(5.297365643089496 2.7414762224995286)'), 'POINT(2.4787445884970523 0.1375109637949761)', ST_GeomFromText('POINT(102.2538858936619 -0.17527166711909103)', 4326), ST_GeomFromText('LINESTRING(1 2,0.6786469240434253 2.211367990743445,0.6813463219532312 2.25715365658098)'), ST_GeomFromText('POLYGON((103.79222557765425 103.5789244283407,95.25401693003629 106.15282128323427,94.01765889123921 98.24868698753832,101.4685328020543 93.3218460619561,103.79222557765425 103.5789244283407))'), X'01010000000000000000000040000000000000E0BF'),
+(3, ST_GeomFromText('POINT(2.9366911398609767 4.1456069442554915)'), 'POINT(3.063080744905485 1.265999518211648)', ST_GeomFromText('POINT(-101.24486268421097 87.75629653629295)', 4326), ST_GeomFromText('LINESTRING(1 2,0.9873501723728101 2.0333828762388504,1.1419593705851785 1.627183783304305)'), ST_GeomFromText('POLYGON((109.50063107619344 101.99970535908676,99.66004414934534 107.65945672477943,94.92563447497639 95.62281110571212,103.70812980570294 91.18115327165448,109.50063107619344 101.99970535908676))'), X'01010000000000000000000840000000000000E0BF'),
+(4, ST_GeomFromText('POINT(4.383094987055445 2.563147100225325)'), 'POINT(2.3388964789677 1.2459295060510789)', ST_GeomFromText('POINT(31.1948539541186 44.10318740375098)', 4326), ST_GeomFromText('LINESTRING(1 2,1.0599808793540275 1.8587638531794608,1.4857619114727831 1.6944282956413783)'), ST_GeomFromText('POLYGON((108.31292425135054 100.83700263111528,97.6622339253326 105.7308404998191,94.79546702663473 95.87618399593484,101.70634678015752 93.23152788351636,108.31292425135054 100.83700263111528))'), X'01010000000000000000001040000000000000E0BF');
@@ -0,0 +1,9 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.point(0, 0, 10, 10) }}
+    {{ st_astext(rand.point_in(array[array[0, 0], array[4, 0], array[4, 4], array[2, 1], array[0, 4]])) }}
+    {{ geography(rand.point(-180, -90, 180, 90)) }}
+    {{ rand.linestring(1, 2, 0.5, 3) }}
+    {{ rand.polygon(100, 100, 10, 4) }}
+    {{ st_asbinary(st_point(rownum, -0.5)) }}
+);
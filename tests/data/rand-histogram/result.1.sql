@@ -0,0 +1,9 @@
+INSERT INTO result VALUES
+(1, 47.376, 1.0, 26.479),
+(2, 553.271, 1.0, 23.896),
+(3, 4.026, 1.0, 45.708),
+(4, 46.666, 1.0, 18.3),
+(5, 63.655, 1.0, 23.353),
+(6, 7.919, 1.0, 45.469),
+(7, 6.29, 1.0, 22.169),
+(8, 9.063, 1.0, 73.715);
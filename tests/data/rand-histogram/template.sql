@@ -0,0 +1,6 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ round(rand.histogram(array[0, 10, 100, 1000], array[50, 45, 5]), 3) }}
+    {{ round(rand.histogram(array[1, 1, 2, 5], array[3, 0, 1]), 3) }}
+    {{ round(rand.quantiles(array[18, 25, 31, 40, 65, 99]), 3) }}
+);
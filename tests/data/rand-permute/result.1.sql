@@ -0,0 +1,12 @@
+INSERT INTO result VALUES
+(1, 16, 879803),
+(2, 2, 543362),
+(3, 20, 724007),
+(4, 6, 737294),
+(5, 5, 913034);
+INSERT INTO result VALUES
+(6, 13, 677984),
+(7, 17, 587805),
+(8, 12, 897450),
+(9, 9, 611459),
+(10, 1, 849735);
@@ -0,0 +1,12 @@
+INSERT INTO result VALUES
+(11, 7, 168157),
+(12, 14, 113915),
+(13, 15, 66298),
+(14, 4, 768651),
+(15, 8, 640447);
+INSERT INTO result VALUES
+(16, 18, 755542),
+(17, 11, 946926),
+(18, 10, 506844),
+(19, 3, 220778),
+(20, 19, 336455);
@@ -0,0 +1,5 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.permute(20, rownum) }}
+    {{ rand.permute(1000000, rownum) }}
+);
@@ -0,0 +1,5 @@
+INSERT INTO result VALUES
+(1, 'Exercitation enim sed et beatae. Et ut error.', 'The fox hides in the sun is gone and the forest. At night', 'The lazy dog sleeps again. A quick red'),
+(2, 'Est ullamco est ab. Excepteur ipsum et unde. Mollit eaque elit non vitae. Et labore ipsa anim.', 'At night the dog wakes up and chases the forest. The quick', 'The forest is quiet at night. The dog'),
+(3, 'Ut ad beatae dolore ut quis et.', 'A quick brown fox hides in the forest. The lazy dog sleeps', 'A quick red fox runs through the forest.'),
+(4, 'In est lorem mollit et deserunt non. Et et anim, pariatur illo.', 'At night the sun is gone and chases the lazy dog. The fox', 'The forest is quiet at night. The dog');
@@ -0,0 +1,6 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ rand.lorem(3, 20) }}
+    {{ rand.markov('corpus.txt', 1, 60) }}
+    {{ rand.markov('corpus.txt', 2, 40) }}
+);
@@ -0,0 +1,3 @@
+INSERT INTO result VALUES
+(5, 620493, 241584),
+(6, 241584, 401688);
@@ -0,0 +1,6 @@
+INSERT INTO result VALUES
+(7, NULL, 104183),
+(8, 104183, 896009),
+(9, 896009, 656387);
+INSERT INTO result VALUES
+(10, 656387, 691005);
@@ -0,0 +1,5 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ @prev }}
+    {{ @prev := rand.range(0, 1000000) }}
+);
@@ -0,0 +1,2 @@
+INSERT INTO result VALUES
+('HELLO, WÖRLD!', 'hello, wörld!', 'Hello World, 3rd Ñandú-Straße', '🥰', 'hi', 'hixyy', 'xxyhi', 'hi  ', 'hi', 'hi  ', 'zzhi', 'hi', 2, '🥰c🥰c🥰', 'abc', '00000042', '42000000', 'ababahi', '   hi', 'hel', '😘😘🥰', 6, '', 'ababab', '', '000001', '-1.500', 3, 0, 3, 9, 2, 1, '🥰😘', '🥰😘', '😘😍', '😘😍', 'abc', '🥰', '😍😘🥰', 'cba', 'b', '', 'c', '', 'b');
@@ -0,0 +1,56 @@
+create table result (
+    {{ upper('hello, wörld!') }}
+    {{ lower('HELLO, WÖRLD!') }}
+    {{ initcap('hello wORLD, 3rd ñandú-straße') }}
+
+    {{ trim('  🥰  ') }}
+    {{ trim(both 'xy' from 'xxyhixyy') }}
+    {{ trim(leading 'xy' from 'xxyhixyy') }}
+    {{ trim(trailing 'xy' from 'xxyhixyy') }}
+    {{ trim(leading from '  hi  ') }}
+    {{ trim('xxhixx', 'x') }}
+    {{ ltrim('  hi  ') }}
+    {{ rtrim('zzhizz', 'z') }}
+    {{ btrim('😘hi😘', '😘') }}
+    {{ octet_length(trim('é' from 'éhié' using octets)) }}
+
+    {{ replace('abcabcab', 'ab', '🥰') }}
+    {{ replace('abc', '', 'x') }}
+
+    {{ lpad('42', 8, '0') }}
+    {{ rpad('42', 8, '0') }}
+    {{ lpad('hi', 7, 'ab') }}
+    {{ lpad('hi', 5) }}
+    {{ rpad('hello', 3) }}
+    {{ lpad('🥰', 3, '😘') }}
+    {{ octet_length(lpad('🥰', 6, '-' using octets)) }}
+    {{ lpad('hi', -1, 'x') }}
+
+    {{ repeat('ab', 3) }}
+    {{ repeat('ab', 0) }}
+    {{ lpad(rownum, 6, '0') }}
+    {{ rpad(-1.5, 6, '0') }}
+
+    {{ position('c' in 'abcde') }}
+    {{ position('z' in 'abcde') }}
+    {{ position('😍' in '🥰😘😍') }}
+    {{ position('😍' in '🥰😘😍' using octets) }}
+    {{ strpos('🥰😘😍', '😘') }}
+    {{ strpos('abc', '') }}
+
+    {{ left('🥰😘😍', 2) }}
+    {{ left('🥰😘😍', -1) }}
+    {{ right('🥰😘😍', 2) }}
+    {{ right('🥰😘😍', -1) }}
+    {{ right('abc', 10) }}
+    {{ left('🥰' || 'abc', 4 using octets) }}
+
+    {{ reverse('🥰😘😍') }}
+    {{ reverse('abc' using octets) }}
+
+    {{ split_part('a,b,,c', ',', 2) }}
+    {{ split_part('a,b,,c', ',', 3) }}
+    {{ split_part('a,b,,c', ',', -1) }}
+    {{ split_part('a,b,,c', ',', 9) }}
+    {{ split_part('a::b::c', '::', 2) }}
+);
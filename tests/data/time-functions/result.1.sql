@@ -0,0 +1,2 @@
+INSERT INTO result VALUES
+(2020, 1, 2, 9, 29, 13, 45, 56, 789000, 6, 6, 60, 1582983956.789, '2020-01-01 00:00:00', '2020-01-01 00:00:00', '2020-02-01 00:00:00', '2020-02-24 00:00:00', '2020-02-29 00:00:00', '2020-02-29 13:00:00', '2020-02-29 13:45:00', '2020-02-29 13:45:56', '2020-03-29 13:45:56.789000', '2020-02-29 00:00:00', '2018-12-29 13:45:56.789000', '2021-02-28 13:45:56.789000', '2020-02-29 00:00:00', 6, 1000000000, '2001-09-09 01:46:40', '1969-12-31 23:59:58.500000', '20200229', '2020-02-29 22:45 JST', '2020-02-29 22:45:56.789000', 19, 1, '2021-03-14 00:00:00', '2021-03-14 03:30:00');
@@ -0,0 +1,43 @@
+/*{{ @ts := timestamp '2020-02-29 13:45:56.789' }}*/
+create table result (
+    {{ extract(year from @ts) }}
+    {{ extract(QUARTER from @ts) }}
+    {{ extract(month from @ts) }}
+    {{ extract(week from @ts) }}
+    {{ extract(day from @ts) }}
+    {{ extract(hour from @ts) }}
+    {{ extract(minute from @ts) }}
+    {{ extract(second from @ts) }}
+    {{ extract(microsecond from @ts) }}
+    {{ extract(dow from @ts) }}
+    {{ extract(isodow from @ts) }}
+    {{ extract(doy from @ts) }}
+    {{ extract('epoch', @ts) }}
+
+    {{ date_trunc('year', @ts) }}
+    {{ date_trunc('quarter', @ts) }}
+    {{ date_trunc('month', @ts) }}
+    {{ date_trunc('week', @ts) }}
+    {{ date_trunc('day', @ts) }}
+    {{ date_trunc('hour', @ts) }}
+    {{ date_trunc('minute', @ts) }}
+    {{ date_trunc('second', @ts) }}
+
+    {{ add_months(@ts, 1) }}
+    {{ add_months(timestamp '2020-01-31 00:00:00', 1) }}
+    {{ add_months(@ts, -14) }}
+    {{ add_years(@ts, 1) }}
+    {{ last_day(@ts) }}
+    {{ day_of_week(@ts) }}
+
+    {{ epoch(timestamp '2001-09-09 01:46:40') }}
+    {{ to_timestamp(1000000000) }}
+    {{ to_timestamp(-1.5) }}
+    {{ format_timestamp(@ts, '%Y%m%d') }}
+    {{ format_timestamp(@ts AT TIME ZONE 'Asia/Tokyo', '%Y-%m-%d %H:%M %Z') }}
+    {{ @ts AT TIME ZONE 'Asia/Tokyo' }}
+    {{ extract(hour from @ts at time zone 'Asia/Kolkata') }}
+    {{ @ts at time zone 'Asia/Tokyo' = @ts }}
+    {{ date_trunc('day', timestamp with time zone '2021-03-14 12:00:00 America/New_York') }}
+    {{ add_months(timestamp with time zone '2021-02-14 02:30:00 America/New_York', 1) }}
+);
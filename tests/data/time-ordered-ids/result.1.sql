@@ -0,0 +1,6 @@
+INSERT INTO result VALUES
+(1, '016f6435-d316-793f-aa1a-0401adaefaac', '01DXJ3BMRP2B22HTFVCJCPA5DB', '1VozTws2MMqx7nmaQLkSrDP2jJL', 1212570233034919937),
+(2, '016f6435-d6fe-7a4b-9bf7-9c493f1e42d5', '01DXJ3BNQYG3Q313FW54VRR0P3', '1VozU5gCKP03ZMC2mXydcDpWOuZ', 1212570237229223938),
+(3, '016f6435-dae6-7b35-aa42-738d1a529399', '01DXJ3BPQ6EZ5K7RMH2AQ7KNX4', '1VozUAGfAYyg3VNeRQgpC4xu16b', 1212570241423527939),
+(4, '016f6435-dece-7bf5-862e-89dfd6f73f1b', '01DXJ3BQPE136FNJ8NT2R977N3', '1VozUHrzXEUqSLT07ZZ6mqY0KR5', 1212570245617831940),
+(5, '016f6435-e2b6-731e-84ee-e73889f4cc1d', '01DXJ3BRNP2XXT7HH9JZ3JJFCS', '1VozUTCULdptBX7ygbHoVZf2NeU', 1212570249812135941);
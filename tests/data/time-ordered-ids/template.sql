@@ -0,0 +1,7 @@
+CREATE TABLE result (
+    {{ rownum }}
+    {{ uuid_v7(@ts := TIMESTAMP '2020-01-02 03:04:05.678' + INTERVAL rownum SECOND) }}
+    {{ ulid(@ts) }}
+    {{ ksuid(@ts) }}
+    {{ snowflake(@ts, 37, rownum) }}
+);